@@ -0,0 +1,35 @@
+use criterion::{ black_box, criterion_group, criterion_main, Criterion };
+use equality::precise::expression::Expr;
+use equality::precise::expression::environment::Environment;
+
+const SOURCE: &str = "sin(x) * cos(y) + x ^ 3 - 2x * y + sqrt(x ^ 2 + y ^ 2) / (1 + exp(-x))";
+
+fn tree_walker(c: &mut Criterion) {
+    let expression: Expr = SOURCE.parse().unwrap();
+    let environment = Environment::new()
+        .with("x", 0.75)
+        .with("y", 1.5);
+
+    c.bench_function("tree walker", |b| {
+        b.iter(|| black_box(&expression).eval_with(black_box(&environment)))
+    });
+}
+
+fn compiled(c: &mut Criterion) {
+    let compiled = SOURCE.parse::<Expr>().unwrap().compile(&["x", "y"]).unwrap();
+
+    c.bench_function("compiled", |b| {
+        b.iter(|| compiled.eval(&[black_box(0.75), black_box(1.5)]))
+    });
+}
+
+fn compilation(c: &mut Criterion) {
+    let expression: Expr = SOURCE.parse().unwrap();
+
+    c.bench_function("compilation", |b| {
+        b.iter(|| black_box(&expression).compile(&["x", "y"]))
+    });
+}
+
+criterion_group!(benches, tree_walker, compiled, compilation);
+criterion_main!(benches);
@@ -1,4 +1,107 @@
+use std::ops::{ Add, Sub, Mul, Div };
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Complex {
     pub real: f64,
     pub imaginary: f64,
 }
+
+impl Complex {
+    pub fn new(real: f64, imaginary: f64) -> Complex {
+        Complex {
+            real,
+            imaginary,
+        }
+    }
+
+    pub fn from_polar(radius: f64, angle: f64) -> Complex {
+        Complex {
+            real: radius * angle.cos(),
+            imaginary: radius * angle.sin(),
+        }
+    }
+
+    pub fn norm(&self) -> f64 {
+        self.real.hypot(self.imaginary)
+    }
+
+    pub fn conjugate(&self) -> Complex {
+        Complex {
+            real: self.real,
+            imaginary: -self.imaginary,
+        }
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(value: f64) -> Self {
+        Complex {
+            real: value,
+            imaginary: 0.0,
+        }
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Complex {
+            real: self.real + rhs.real,
+            imaginary: self.imaginary + rhs.imaginary,
+        }
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Complex {
+            real: self.real - rhs.real,
+            imaginary: self.imaginary - rhs.imaginary,
+        }
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Complex {
+            real: self.real * rhs.real - self.imaginary * rhs.imaginary,
+            imaginary: self.real * rhs.imaginary + self.imaginary * rhs.real,
+        }
+    }
+}
+
+impl Div for Complex {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let denominator = rhs.real * rhs.real + rhs.imaginary * rhs.imaginary;
+
+        Complex {
+            real: (self.real * rhs.real + self.imaginary * rhs.imaginary) / denominator,
+            imaginary: (self.imaginary * rhs.real - self.real * rhs.imaginary) / denominator,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Complex;
+
+    #[test]
+    fn arithmetic() {
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, -1.0);
+
+        assert_eq!(a + b, Complex::new(4.0, 1.0));
+        assert_eq!(a - b, Complex::new(-2.0, 3.0));
+        assert_eq!(a * b, Complex::new(5.0, 5.0));
+        assert_eq!((a * b) / b, a);
+        assert_eq!(a.conjugate(), Complex::new(1.0, -2.0));
+        assert_eq!(Complex::new(3.0, 4.0).norm(), 5.0);
+    }
+}
@@ -1,3 +1,7 @@
 pub mod expression;
+pub mod integer;
+pub mod rational;
 pub mod algebra;
 pub mod complex;
+pub mod equation;
+pub mod trace;
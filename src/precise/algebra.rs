@@ -1 +1,2 @@
 pub mod linear;
+pub mod polynomial;
@@ -7,6 +7,33 @@ pub struct Matrix2 {
     data: [Expr; 4],
 }
 
+impl Default for Matrix2 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Matrix2 {
+    pub fn new() -> Matrix2 {
+        Matrix2 {
+            data: [
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+            ]
+        }
+    }
+
+    pub fn to_latex(&self) -> String {
+        let rows: Vec<String> = self.data.chunks(2)
+            .map(|row| row.iter().map(Expr::to_latex).collect::<Vec<String>>().join(" & "))
+            .collect();
+
+        format!("\\begin{{bmatrix}} {} \\end{{bmatrix}}", rows.join(" \\\\ "))
+    }
+}
+
 impl Index<usize> for Matrix2 {
     type Output = Expr;
 
@@ -20,3 +47,25 @@ impl IndexMut<usize> for Matrix2 {
         &mut self.data[index]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Matrix2;
+    use crate::precise::expression::Expr;
+
+    #[test]
+    fn to_latex() {
+        let mut matrix = Matrix2::new();
+
+        for index in 0..2 {
+            matrix[index * 2 + index] = Expr::from(1);
+        }
+
+        matrix[0] = Expr::var("x");
+
+        let result = matrix.to_latex();
+        let expected_result = "\\begin{bmatrix} x & 0 \\\\ 0 & 1 \\end{bmatrix}";
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -7,6 +7,38 @@ pub struct Matrix3 {
     data: [Expr; 9],
 }
 
+impl Default for Matrix3 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Matrix3 {
+    pub fn new() -> Matrix3 {
+        Matrix3 {
+            data: [
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+            ]
+        }
+    }
+
+    pub fn to_latex(&self) -> String {
+        let rows: Vec<String> = self.data.chunks(3)
+            .map(|row| row.iter().map(Expr::to_latex).collect::<Vec<String>>().join(" & "))
+            .collect();
+
+        format!("\\begin{{bmatrix}} {} \\end{{bmatrix}}", rows.join(" \\\\ "))
+    }
+}
+
 impl Index<usize> for Matrix3 {
     type Output = Expr;
 
@@ -20,3 +52,25 @@ impl IndexMut<usize> for Matrix3 {
         &mut self.data[index]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Matrix3;
+    use crate::precise::expression::Expr;
+
+    #[test]
+    fn to_latex() {
+        let mut matrix = Matrix3::new();
+
+        for index in 0..3 {
+            matrix[index * 3 + index] = Expr::from(1);
+        }
+
+        matrix[0] = Expr::var("x");
+
+        let result = matrix.to_latex();
+        let expected_result = "\\begin{bmatrix} x & 0 & 0 \\\\ 0 & 1 & 0 \\\\ 0 & 0 & 1 \\end{bmatrix}";
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -7,6 +7,45 @@ pub struct Matrix4 {
     data: [Expr; 16],
 }
 
+impl Default for Matrix4 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Matrix4 {
+    pub fn new() -> Matrix4 {
+        Matrix4 {
+            data: [
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+                Expr::from(0),
+            ]
+        }
+    }
+
+    pub fn to_latex(&self) -> String {
+        let rows: Vec<String> = self.data.chunks(4)
+            .map(|row| row.iter().map(Expr::to_latex).collect::<Vec<String>>().join(" & "))
+            .collect();
+
+        format!("\\begin{{bmatrix}} {} \\end{{bmatrix}}", rows.join(" \\\\ "))
+    }
+}
+
 impl Index<usize> for Matrix4 {
     type Output = Expr;
 
@@ -20,3 +59,25 @@ impl IndexMut<usize> for Matrix4 {
         &mut self.data[index]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Matrix4;
+    use crate::precise::expression::Expr;
+
+    #[test]
+    fn to_latex() {
+        let mut matrix = Matrix4::new();
+
+        for index in 0..4 {
+            matrix[index * 4 + index] = Expr::from(1);
+        }
+
+        matrix[0] = Expr::var("x");
+
+        let result = matrix.to_latex();
+        let expected_result = "\\begin{bmatrix} x & 0 & 0 & 0 \\\\ 0 & 1 & 0 & 0 \\\\ 0 & 0 & 1 & 0 \\\\ 0 & 0 & 0 & 1 \\end{bmatrix}";
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -48,18 +48,24 @@ impl Vector2 {
     pub fn component(self, rhs: Self) -> Self {
         Vector2 {
             data: [
-                self[0].clone() * rhs[0].clone(),
-                self[1].clone() * rhs[1].clone(),
+                &self[0] * &rhs[0],
+                &self[1] * &rhs[1],
             ]
         }
     }
 
     pub fn norm(&self) -> Expr {
-        ((self.x().clone() ^ 2) + (self.y().clone() ^ 2)) ^ 0.5
+        ((self.x() ^ 2) + (self.y() ^ 2)) ^ 0.5
     }
 
     pub fn normalize(self) -> Vector2 {
-        self.clone() * (1 / self.norm())
+        let norm = self.norm();
+
+        self * (1 / norm)
+    }
+
+    pub fn to_latex(&self) -> String {
+        format!("\\begin{{pmatrix}} {} \\\\ {} \\end{{pmatrix}}", self[0].to_latex(), self[1].to_latex())
     }
 }
 
@@ -67,7 +73,7 @@ impl Add for Vector2 {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Vector2::from((self[0].clone() + rhs[0].clone(), self[1].clone() + rhs[1].clone()))
+        Vector2::from((&self[0] + &rhs[0], &self[1] + &rhs[1]))
     }
 }
 
@@ -75,7 +81,7 @@ impl Sub for Vector2 {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        Vector2::from((self[0].clone() - rhs[0].clone(), self[1].clone() - rhs[1].clone()))
+        Vector2::from((&self[0] - &rhs[0], &self[1] - &rhs[1]))
     }
 }
 
@@ -83,7 +89,7 @@ impl Mul for Vector2 {
     type Output = Expr;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        self[0].clone() * rhs[0].clone() + self[1].clone() * rhs[1].clone()
+        &self[0] * &rhs[0] + &self[1] * &rhs[1]
     }
 }
 
@@ -91,7 +97,7 @@ impl Mul<Expr> for Vector2 {
     type Output = Self;
 
     fn mul(self, rhs: Expr) -> Self::Output {
-        Vector2::from((self[0].clone() * rhs.clone(), self[1].clone() * rhs))
+        Vector2::from((&self[0] * &rhs, &self[1] * rhs))
     }
 }
 
@@ -101,8 +107,8 @@ impl Mul<i16> for Vector2 {
     fn mul(self, rhs: i16) -> Self::Output {
         Vector2 {
             data: [
-                self[0].clone() * rhs,
-                self[1].clone() * rhs,
+                &self[0] * rhs,
+                &self[1] * rhs,
             ]
         }
     }
@@ -114,8 +120,8 @@ impl Mul<i32> for Vector2 {
     fn mul(self, rhs: i32) -> Self::Output {
         Vector2 {
             data: [
-                self[0].clone() * rhs,
-                self[1].clone() * rhs,
+                &self[0] * rhs,
+                &self[1] * rhs,
             ]
         }
     }
@@ -127,8 +133,8 @@ impl Mul<f32> for Vector2 {
     fn mul(self, rhs: f32) -> Self::Output {
         Vector2 {
             data: [
-                self[0].clone() * rhs,
-                self[1].clone() * rhs,
+                &self[0] * rhs,
+                &self[1] * rhs,
             ]
         }
     }
@@ -140,8 +146,8 @@ impl Mul<f64> for Vector2 {
     fn mul(self, rhs: f64) -> Self::Output {
         Vector2 {
             data: [
-                self[0].clone() * rhs,
-                self[1].clone() * rhs,
+                &self[0] * rhs,
+                &self[1] * rhs,
             ]
         }
     }
@@ -587,4 +593,12 @@ mod tests {
         assert_eq!(*result1, expected_result1);
         assert_eq!(*result2, expected_result2);
     }
+
+    #[test]
+    fn to_latex() {
+        let result = Vector2::from((3, 5)).to_latex();
+        let expected_result = "\\begin{pmatrix} 3 \\\\ 5 \\end{pmatrix}";
+
+        assert_eq!(result, expected_result);
+    }
 }
@@ -57,19 +57,25 @@ impl Vector3 {
     pub fn component(self, rhs: Self) -> Self {
         Vector3 {
             data: [
-                self[0].clone() * rhs[0].clone(),
-                self[1].clone() * rhs[1].clone(),
-                self[2].clone() * rhs[2].clone(),
+                &self[0] * &rhs[0],
+                &self[1] * &rhs[1],
+                &self[2] * &rhs[2],
             ]
         }
     }
 
     pub fn norm(&self) -> Expr {
-        ((self.x().clone() ^ 2) + (self.y().clone() ^ 2) + (self.z().clone() ^ 2)) ^ 0.5
+        ((self.x() ^ 2) + (self.y() ^ 2) + (self.z() ^ 2)) ^ 0.5
     }
 
     pub fn normalize(self) -> Vector3 {
-        self.clone() * (1 / self.norm())
+        let norm = self.norm();
+
+        self * (1 / norm)
+    }
+
+    pub fn to_latex(&self) -> String {
+        format!("\\begin{{pmatrix}} {} \\\\ {} \\\\ {} \\end{{pmatrix}}", self[0].to_latex(), self[1].to_latex(), self[2].to_latex())
     }
 }
 
@@ -77,7 +83,7 @@ impl Add for Vector3 {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Vector3::from((self[0].clone() + rhs[0].clone(), self[1].clone() + rhs[1].clone(), self[2].clone() + rhs[2].clone()))
+        Vector3::from((&self[0] + &rhs[0], &self[1] + &rhs[1], &self[2] + &rhs[2]))
     }
 }
 
@@ -85,7 +91,7 @@ impl Sub for Vector3 {
     type Output = Self;
     
     fn sub(self, rhs: Self) -> Self::Output {
-        Vector3::from((self[0].clone() - rhs[0].clone(), self[1].clone() - rhs[1].clone(), self[2].clone() - rhs[2].clone()))
+        Vector3::from((&self[0] - &rhs[0], &self[1] - &rhs[1], &self[2] - &rhs[2]))
     }
 }
 
@@ -93,7 +99,7 @@ impl Mul for Vector3 {
     type Output = Expr;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        self[0].clone() * rhs[0].clone() + self[1].clone() * rhs[1].clone() + self[2].clone() * rhs[2].clone()
+        &self[0] * &rhs[0] + &self[1] * &rhs[1] + &self[2] * &rhs[2]
     }
 }
 
@@ -101,7 +107,7 @@ impl Mul<Expr> for Vector3 {
     type Output = Self;
 
     fn mul(self, rhs: Expr) -> Self::Output {
-        Vector3::from((self[0].clone() * rhs.clone(), self[1].clone() * rhs.clone(), self[2].clone() * rhs.clone()))
+        Vector3::from((&self[0] * &rhs, &self[1] * &rhs, &self[2] * &rhs))
     }
 }
 
@@ -111,9 +117,9 @@ impl Mul<i16> for Vector3 {
     fn mul(self, rhs: i16) -> Self::Output {
         Vector3 {
             data: [
-                self[0].clone() * rhs,
-                self[1].clone() * rhs,
-                self[2].clone() * rhs,
+                &self[0] * rhs,
+                &self[1] * rhs,
+                &self[2] * rhs,
             ]
         }
     }
@@ -125,9 +131,9 @@ impl Mul<i32> for Vector3 {
     fn mul(self, rhs: i32) -> Self::Output {
         Vector3 {
             data: [
-                self[0].clone() * rhs,
-                self[1].clone() * rhs,
-                self[2].clone() * rhs,
+                &self[0] * rhs,
+                &self[1] * rhs,
+                &self[2] * rhs,
             ]
         }
     }
@@ -139,9 +145,9 @@ impl Mul<f32> for Vector3 {
     fn mul(self, rhs: f32) -> Self::Output {
         Vector3 {
             data: [
-                self[0].clone() * rhs,
-                self[1].clone() * rhs,
-                self[2].clone() * rhs,
+                &self[0] * rhs,
+                &self[1] * rhs,
+                &self[2] * rhs,
             ]
         }
     }
@@ -153,9 +159,9 @@ impl Mul<f64> for Vector3 {
     fn mul(self, rhs: f64) -> Self::Output {
         Vector3 {
             data: [
-                self[0].clone() * rhs,
-                self[1].clone() * rhs,
-                self[2].clone() * rhs,
+                &self[0] * rhs,
+                &self[1] * rhs,
+                &self[2] * rhs,
             ]
         }
     }
@@ -644,4 +650,12 @@ mod tests {
         assert_eq!(*result2, expected_result2);
         assert_eq!(*result3, expected_result3);
     }
+
+    #[test]
+    fn to_latex() {
+        let result = Vector3::from((3, 5, 7)).to_latex();
+        let expected_result = "\\begin{pmatrix} 3 \\\\ 5 \\\\ 7 \\end{pmatrix}";
+
+        assert_eq!(result, expected_result);
+    }
 }
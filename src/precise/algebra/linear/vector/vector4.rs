@@ -66,20 +66,26 @@ impl Vector4 {
     pub fn component(self, rhs: Self) -> Self {
         Vector4 {
             data: [
-                self[0].clone() * rhs[0].clone(),
-                self[1].clone() * rhs[1].clone(),
-                self[2].clone() * rhs[2].clone(),
-                self[3].clone() * rhs[3].clone(),
+                &self[0] * &rhs[0],
+                &self[1] * &rhs[1],
+                &self[2] * &rhs[2],
+                &self[3] * &rhs[3],
             ]
         }
     }
 
     pub fn norm(&self) -> Expr {
-        ((self.x().clone() ^ 2) + (self.y().clone() ^ 2) + (self.z().clone() ^ 2) + (self.w().clone() ^ 2)) ^ 0.5
+        ((self.x() ^ 2) + (self.y() ^ 2) + (self.z() ^ 2) + (self.w() ^ 2)) ^ 0.5
     }
 
     pub fn normalize(self) -> Vector4 {
-        self.clone() * (1 / self.norm())
+        let norm = self.norm();
+
+        self * (1 / norm)
+    }
+
+    pub fn to_latex(&self) -> String {
+        format!("\\begin{{pmatrix}} {} \\\\ {} \\\\ {} \\\\ {} \\end{{pmatrix}}", self[0].to_latex(), self[1].to_latex(), self[2].to_latex(), self[3].to_latex())
     }
 }
 
@@ -87,7 +93,7 @@ impl Add for Vector4 {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self {
-        Vector4::from((self[0].clone() + rhs[0].clone(), self[1].clone() + rhs[1].clone(), self[2].clone() + rhs[2].clone(), self[3].clone() + rhs[3].clone()))
+        Vector4::from((&self[0] + &rhs[0], &self[1] + &rhs[1], &self[2] + &rhs[2], &self[3] + &rhs[3]))
     }
 }
 
@@ -95,7 +101,7 @@ impl Sub for Vector4 {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self {
-        Vector4::from((self[0].clone() - rhs[0].clone(), self[1].clone() - rhs[1].clone(), self[2].clone() - rhs[2].clone(), self[3].clone() - rhs[3].clone()))
+        Vector4::from((&self[0] - &rhs[0], &self[1] - &rhs[1], &self[2] - &rhs[2], &self[3] - &rhs[3]))
     }
 }
 
@@ -103,7 +109,7 @@ impl Mul for Vector4 {
     type Output = Expr;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        self[0].clone() * rhs[0].clone() + self[1].clone() * rhs[1].clone() + self[2].clone() * rhs[2].clone() + self[3].clone() * rhs[3].clone()
+        &self[0] * &rhs[0] + &self[1] * &rhs[1] + &self[2] * &rhs[2] + &self[3] * &rhs[3]
     }
 }
 
@@ -113,10 +119,10 @@ impl Mul<Expr> for Vector4 {
     fn mul(self, rhs: Expr) -> Self::Output {
         Vector4 {
             data: [
-                self[0].clone() * rhs.clone(),
-                self[1].clone() * rhs.clone(),
-                self[2].clone() * rhs.clone(),
-                self[3].clone() * rhs,
+                &self[0] * &rhs,
+                &self[1] * &rhs,
+                &self[2] * &rhs,
+                &self[3] * rhs,
             ]
         }
     }
@@ -128,10 +134,10 @@ impl Mul<i16> for Vector4 {
     fn mul(self, rhs: i16) -> Self::Output {
         Vector4 {
             data: [
-                self[0].clone() * rhs,
-                self[1].clone() * rhs,
-                self[2].clone() * rhs,
-                self[3].clone() * rhs,
+                &self[0] * rhs,
+                &self[1] * rhs,
+                &self[2] * rhs,
+                &self[3] * rhs,
             ]
         }
     }
@@ -143,10 +149,10 @@ impl Mul<i32> for Vector4 {
     fn mul(self, rhs: i32) -> Self::Output {
         Vector4 {
             data: [
-                self[0].clone() * rhs,
-                self[1].clone() * rhs,
-                self[2].clone() * rhs,
-                self[3].clone() * rhs,
+                &self[0] * rhs,
+                &self[1] * rhs,
+                &self[2] * rhs,
+                &self[3] * rhs,
             ]
         }
     }
@@ -158,10 +164,10 @@ impl Mul<f32> for Vector4 {
     fn mul(self, rhs: f32) -> Self::Output {
         Vector4 {
             data: [
-                self[0].clone() * rhs,
-                self[1].clone() * rhs,
-                self[2].clone() * rhs,
-                self[3].clone() * rhs,
+                &self[0] * rhs,
+                &self[1] * rhs,
+                &self[2] * rhs,
+                &self[3] * rhs,
             ]
         }
     }
@@ -173,10 +179,10 @@ impl Mul<f64> for Vector4 {
     fn mul(self, rhs: f64) -> Self::Output {
         Vector4 {
             data: [
-                self[0].clone() * rhs,
-                self[1].clone() * rhs,
-                self[2].clone() * rhs,
-                self[3].clone() * rhs,
+                &self[0] * rhs,
+                &self[1] * rhs,
+                &self[2] * rhs,
+                &self[3] * rhs,
             ]
         }
     }
@@ -708,4 +714,12 @@ mod tests {
         assert_eq!(*result3, expected_result3);
         assert_eq!(*result4, expected_result4);
     }
+
+    #[test]
+    fn to_latex() {
+        let result = Vector4::from((3, 5, 7, 9)).to_latex();
+        let expected_result = "\\begin{pmatrix} 3 \\\\ 5 \\\\ 7 \\\\ 9 \\end{pmatrix}";
+
+        assert_eq!(result, expected_result);
+    }
 }
@@ -0,0 +1,524 @@
+use std::fmt;
+use std::ops::{ Add, Sub, Mul, Div, Rem, Neg };
+use std::sync::Arc;
+use serde_derive::{ Serialize, Deserialize };
+use crate::precise::expression::Expr;
+use crate::precise::expression::simplify::as_number;
+use crate::precise::integer::Integer;
+use crate::precise::rational::Rational;
+
+pub mod factor;
+pub mod roots;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "RawPolynomial")]
+pub struct Polynomial {
+    coefficients: Vec<Rational>,
+}
+
+#[derive(Deserialize)]
+struct RawPolynomial {
+    coefficients: Vec<Rational>,
+}
+
+impl From<RawPolynomial> for Polynomial {
+    fn from(raw: RawPolynomial) -> Self {
+        Polynomial::new(raw.coefficients)
+    }
+}
+
+impl Polynomial {
+    pub fn new(coefficients: Vec<Rational>) -> Polynomial {
+        let mut polynomial = Polynomial {
+            coefficients,
+        };
+
+        polynomial.normalize();
+        polynomial
+    }
+
+    pub fn zero() -> Polynomial {
+        Polynomial {
+            coefficients: Vec::new(),
+        }
+    }
+
+    pub fn one() -> Polynomial {
+        Polynomial::constant(Rational::one())
+    }
+
+    pub fn constant(value: Rational) -> Polynomial {
+        Polynomial::new(vec![value])
+    }
+
+    pub fn monomial(coefficient: Rational, degree: usize) -> Polynomial {
+        let mut coefficients = vec![Rational::zero(); degree];
+        coefficients.push(coefficient);
+
+        Polynomial::new(coefficients)
+    }
+
+    fn normalize(&mut self) {
+        while self.coefficients.last().is_some_and(Rational::is_zero) {
+            self.coefficients.pop();
+        }
+    }
+
+    pub fn coefficients(&self) -> &[Rational] {
+        &self.coefficients
+    }
+
+    pub fn coefficient(&self, degree: usize) -> Rational {
+        self.coefficients.get(degree).cloned().unwrap_or_else(Rational::zero)
+    }
+
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    pub fn leading_coefficient(&self) -> Rational {
+        self.coefficients.last().cloned().unwrap_or_else(Rational::zero)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    pub fn is_constant(&self) -> bool {
+        self.coefficients.len() <= 1
+    }
+
+    pub fn eval(&self, x: &Rational) -> Rational {
+        self.coefficients.iter()
+            .rev()
+            .fold(Rational::zero(), |result, coefficient| &(&result * x) + coefficient)
+    }
+
+    pub fn eval_f64(&self, x: f64) -> f64 {
+        self.coefficients.iter()
+            .rev()
+            .fold(0.0, |result, coefficient| result * x + coefficient.to_f64())
+    }
+
+    pub fn derivative(&self) -> Polynomial {
+        Polynomial::new(
+            self.coefficients.iter()
+                .enumerate()
+                .skip(1)
+                .map(|(degree, coefficient)| coefficient * &Rational::from(degree as i128))
+                .collect()
+        )
+    }
+
+    pub fn monic(&self) -> Polynomial {
+        if self.is_zero() {
+            return self.clone();
+        }
+
+        let leading = self.leading_coefficient();
+
+        Polynomial::new(self.coefficients.iter().map(|coefficient| coefficient / &leading).collect())
+    }
+
+    pub fn div_rem(&self, rhs: &Polynomial) -> (Polynomial, Polynomial) {
+        let divisor_degree = match rhs.degree() {
+            Some(degree) => degree,
+            None => panic!("attempt to divide by zero"),
+        };
+
+        let leading = rhs.leading_coefficient();
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![Rational::zero(); remainder.len().saturating_sub(divisor_degree)];
+
+        while remainder.len() > divisor_degree {
+            let shift = remainder.len() - 1 - divisor_degree;
+            let factor = &remainder[remainder.len() - 1] / &leading;
+
+            for (degree, coefficient) in rhs.coefficients.iter().enumerate() {
+                remainder[shift + degree] = &remainder[shift + degree] - &(&factor * coefficient);
+            }
+
+            remainder.pop();
+            quotient[shift] = factor;
+        }
+
+        (Polynomial::new(quotient), Polynomial::new(remainder))
+    }
+
+    pub fn gcd(&self, rhs: &Polynomial) -> Polynomial {
+        let mut a = self.clone();
+        let mut b = rhs.clone();
+
+        while !b.is_zero() {
+            let remainder = &a % &b;
+            a = b;
+            b = remainder;
+        }
+
+        a.monic()
+    }
+
+    pub fn compose(&self, inner: &Polynomial) -> Polynomial {
+        self.coefficients.iter()
+            .rev()
+            .fold(Polynomial::zero(), |result, coefficient| &(&result * inner) + &Polynomial::constant(coefficient.clone()))
+    }
+
+    pub fn from_expr(expression: &Expr, name: &str) -> Option<Polynomial> {
+        if let Some(value) = as_number(expression) {
+            return Some(Polynomial::constant(value));
+        }
+
+        match expression {
+            Expr::Val(x) => Some(Polynomial::constant(Rational::from(x.clone()))),
+            Expr::Var(x) if x == name => Some(Polynomial::monomial(Rational::one(), 1)),
+            Expr::Add(x, y) => Some(Polynomial::from_expr(x, name)? + Polynomial::from_expr(y, name)?),
+            Expr::Sub(x, y) => Some(Polynomial::from_expr(x, name)? - Polynomial::from_expr(y, name)?),
+            Expr::Mul(x, y) => Some(Polynomial::from_expr(x, name)? * Polynomial::from_expr(y, name)?),
+            Expr::Div(x, y) => {
+                let divisor = Polynomial::from_expr(y, name)?;
+
+                if divisor.is_zero() || !divisor.is_constant() {
+                    return None;
+                }
+
+                Some(Polynomial::from_expr(x, name)? / divisor)
+            },
+            Expr::Exp(x, y) => {
+                let exponent = as_number(y).filter(|exponent| exponent.is_integer() && !exponent.is_negative())?;
+                let exponent = exponent.numerator().to_u32()?;
+
+                Some(Polynomial::from_expr(x, name)?.pow(exponent))
+            },
+            _ => None,
+        }
+    }
+
+    pub fn pow(&self, exponent: u32) -> Polynomial {
+        let mut result = Polynomial::one();
+        let mut base = self.clone();
+        let mut exponent = exponent;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+
+            exponent >>= 1;
+
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+
+        result
+    }
+
+    pub fn to_expr(&self, name: &str) -> Expr {
+        let mut result: Option<Expr> = None;
+
+        for (degree, coefficient) in self.coefficients.iter().enumerate().rev() {
+            if coefficient.is_zero() {
+                continue;
+            }
+
+            let magnitude = match result {
+                Some(_) => coefficient.abs(),
+                None => coefficient.clone(),
+            };
+
+            let term = term(&magnitude, degree, name);
+
+            result = Some(match result {
+                None => term,
+                Some(lhs) if coefficient.is_negative() => Expr::Sub(Arc::new(lhs), Arc::new(term)),
+                Some(lhs) => Expr::Add(Arc::new(lhs), Arc::new(term)),
+            });
+        }
+
+        result.unwrap_or_else(|| Expr::from(0))
+    }
+}
+
+fn term(coefficient: &Rational, degree: usize, name: &str) -> Expr {
+    let numerator = coefficient.numerator();
+    let denominator = coefficient.denominator();
+
+    let power = match degree {
+        0 => None,
+        1 => Some(Expr::Var(name.to_string())),
+        _ => Some(Expr::Exp(Arc::new(Expr::Var(name.to_string())), Arc::new(Expr::from(degree as u64)))),
+    };
+
+    let numerator = match power {
+        None => Expr::from(numerator.clone()),
+        Some(power) if numerator.is_one() => power,
+        Some(power) => Expr::Mul(Arc::new(Expr::from(numerator.clone())), Arc::new(power)),
+    };
+
+    if denominator.is_one() {
+        numerator
+    } else {
+        Expr::Div(Arc::new(numerator), Arc::new(Expr::from(denominator.clone())))
+    }
+}
+
+impl From<Rational> for Polynomial {
+    fn from(value: Rational) -> Self {
+        Polynomial::constant(value)
+    }
+}
+
+impl From<Integer> for Polynomial {
+    fn from(value: Integer) -> Self {
+        Polynomial::constant(Rational::from(value))
+    }
+}
+
+impl<'a> Add<&'a Polynomial> for &'a Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, rhs: &Polynomial) -> Self::Output {
+        let length = self.coefficients.len().max(rhs.coefficients.len());
+
+        Polynomial::new((0..length).map(|degree| &self.coefficient(degree) + &rhs.coefficient(degree)).collect())
+    }
+}
+
+impl Add for Polynomial {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl<'a> Sub<&'a Polynomial> for &'a Polynomial {
+    type Output = Polynomial;
+
+    fn sub(self, rhs: &Polynomial) -> Self::Output {
+        self + &-rhs
+    }
+}
+
+impl Sub for Polynomial {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl<'a> Mul<&'a Polynomial> for &'a Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: &Polynomial) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return Polynomial::zero();
+        }
+
+        let mut coefficients = vec![Rational::zero(); self.coefficients.len() + rhs.coefficients.len() - 1];
+
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in rhs.coefficients.iter().enumerate() {
+                coefficients[i + j] = &coefficients[i + j] + &(a * b);
+            }
+        }
+
+        Polynomial::new(coefficients)
+    }
+}
+
+impl Mul for Polynomial {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<'a> Div<&'a Polynomial> for &'a Polynomial {
+    type Output = Polynomial;
+
+    fn div(self, rhs: &Polynomial) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Div for Polynomial {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+impl<'a> Rem<&'a Polynomial> for &'a Polynomial {
+    type Output = Polynomial;
+
+    fn rem(self, rhs: &Polynomial) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl Rem for Polynomial {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        &self % &rhs
+    }
+}
+
+impl Neg for &Polynomial {
+    type Output = Polynomial;
+
+    fn neg(self) -> Self::Output {
+        Polynomial {
+            coefficients: self.coefficients.iter().map(|coefficient| -coefficient).collect(),
+        }
+    }
+}
+
+impl Neg for Polynomial {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_expr("x"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Polynomial;
+    use crate::precise::expression::Expr;
+    use crate::precise::rational::Rational;
+
+    fn polynomial(coefficients: &[i128]) -> Polynomial {
+        Polynomial::new(coefficients.iter().map(|coefficient| Rational::from(*coefficient)).collect())
+    }
+
+    fn parse(source: &str) -> Polynomial {
+        Polynomial::from_expr(&source.parse().unwrap(), "x").unwrap()
+    }
+
+    #[test]
+    fn normalize() {
+        let result = polynomial(&[1, 2, 0, 0]);
+
+        assert_eq!(result.degree(), Some(1));
+        assert_eq!(polynomial(&[0, 0]).degree(), None);
+        assert!(polynomial(&[0]).is_zero());
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{"coefficients":[{"numerator":1,"denominator":1},{"numerator":0,"denominator":1}]}"#;
+        let result: Polynomial = serde_json::from_str(json).unwrap();
+
+        assert_eq!(result, polynomial(&[1]));
+        assert_eq!(result.degree(), Some(0));
+        assert_eq!(serde_json::from_str::<Polynomial>(&serde_json::to_string(&polynomial(&[1, 2])).unwrap()).unwrap(), polynomial(&[1, 2]));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = polynomial(&[1, 1]);
+        let b = polynomial(&[-1, 1]);
+
+        assert_eq!(&a * &b, polynomial(&[-1, 0, 1]));
+        assert_eq!(&a + &b, polynomial(&[0, 2]));
+        assert_eq!(&a - &a, Polynomial::zero());
+        assert_eq!(-a.clone(), polynomial(&[-1, -1]));
+        assert_eq!(a.pow(3), polynomial(&[1, 3, 3, 1]));
+    }
+
+    #[test]
+    fn div_rem() {
+        let (quotient, remainder) = polynomial(&[-4, 0, -2, 1]).div_rem(&polynomial(&[-3, 1]));
+
+        assert_eq!(quotient, polynomial(&[3, 1, 1]));
+        assert_eq!(remainder, polynomial(&[5]));
+
+        let (quotient, remainder) = polynomial(&[1, 0, 1]).div_rem(&polynomial(&[0, 2]));
+
+        assert_eq!(quotient, Polynomial::new(vec![Rational::zero(), Rational::new(1, 2)]));
+        assert_eq!(remainder, polynomial(&[1]));
+
+        let (quotient, remainder) = polynomial(&[1, 1]).div_rem(&polynomial(&[0, 0, 1]));
+
+        assert_eq!(quotient, Polynomial::zero());
+        assert_eq!(remainder, polynomial(&[1, 1]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn division_by_zero() {
+        let _ = polynomial(&[1, 1]).div_rem(&Polynomial::zero());
+    }
+
+    #[test]
+    fn gcd() {
+        let a = polynomial(&[-1, 0, 1]);
+        let b = polynomial(&[2, 4, 2]);
+
+        assert_eq!(a.gcd(&b), polynomial(&[1, 1]));
+        assert_eq!(polynomial(&[1, 1]).gcd(&polynomial(&[2, 1])), Polynomial::one());
+        assert_eq!(Polynomial::zero().gcd(&polynomial(&[3, 6])), Polynomial::new(vec![Rational::new(1, 2), Rational::one()]));
+    }
+
+    #[test]
+    fn derivative() {
+        assert_eq!(polynomial(&[5, 3, 0, 2]).derivative(), polynomial(&[3, 0, 6]));
+        assert_eq!(polynomial(&[5]).derivative(), Polynomial::zero());
+    }
+
+    #[test]
+    fn eval() {
+        let p = polynomial(&[1, -3, 2]);
+
+        assert_eq!(p.eval(&Rational::new(1, 2)), Rational::zero());
+        assert_eq!(p.eval(&Rational::from(3)), Rational::from(10));
+        assert_eq!(p.eval_f64(3.0), 10.0);
+    }
+
+    #[test]
+    fn compose() {
+        let p = polynomial(&[1, 0, 1]);
+        let q = polynomial(&[1, 1]);
+
+        assert_eq!(p.compose(&q), polynomial(&[2, 2, 1]));
+        assert_eq!(q.compose(&p), polynomial(&[2, 0, 1]));
+    }
+
+    #[test]
+    fn from_expr() {
+        assert_eq!(parse("(x + 1) ^ 2 - 2x"), polynomial(&[1, 0, 1]));
+        assert_eq!(parse("x / 2 + 3 / 4"), Polynomial::new(vec![Rational::new(3, 4), Rational::new(1, 2)]));
+        assert_eq!(Polynomial::from_expr(&"x ^ 2 + y".parse().unwrap(), "x"), None);
+        assert_eq!(Polynomial::from_expr(&"1 / x".parse().unwrap(), "x"), None);
+        assert_eq!(Polynomial::from_expr(&"sin(x)".parse().unwrap(), "x"), None);
+    }
+
+    #[test]
+    fn to_expr() {
+        let p = Polynomial::new(vec![Rational::new(1, 2), Rational::from(-1), Rational::zero(), Rational::from(-3)]);
+        let x = Rational::new(2, 3);
+
+        assert_eq!(p.to_expr("t").to_string(), "-3 * t ^ 3 - t + 1 / 2");
+        assert_eq!(p.to_expr("x").substitute("x", Expr::from(x.clone())).eval_exact(), Ok(p.eval(&x)));
+        assert_eq!(Polynomial::zero().to_expr("x").to_string(), "0");
+        assert_eq!(p.to_expr("e").to_string(), "-3 * e ^ 3 - e + 1 / 2");
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(parse("x ^ 2 / 3 + 2x - 1").to_string(), "x ^ 2 / 3 + 2 * x - 1");
+        assert_eq!(polynomial(&[0, -1]).to_string(), "-x");
+    }
+}
@@ -0,0 +1,265 @@
+use std::fmt;
+use std::sync::Arc;
+use crate::precise::algebra::polynomial::Polynomial;
+use crate::precise::expression::Expr;
+use crate::precise::integer::Integer;
+use crate::precise::rational::Rational;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Factorization {
+    content: Rational,
+    factors: Vec<(Polynomial, u32)>,
+}
+
+impl Factorization {
+    pub fn content(&self) -> &Rational {
+        &self.content
+    }
+
+    pub fn factors(&self) -> &[(Polynomial, u32)] {
+        &self.factors
+    }
+
+    pub fn expand(&self) -> Polynomial {
+        self.factors.iter()
+            .fold(Polynomial::constant(self.content.clone()), |result, (factor, multiplicity)| &result * &factor.pow(*multiplicity))
+    }
+
+    fn product(&self, name: &str, leading: Option<Expr>) -> Option<Expr> {
+        let mut result = leading;
+
+        for (factor, multiplicity) in self.factors.iter() {
+            let factor = match multiplicity {
+                1 => factor.to_expr(name),
+                _ => Expr::Exp(Arc::new(factor.to_expr(name)), Arc::new(Expr::from(*multiplicity))),
+            };
+
+            result = Some(match result {
+                None => factor,
+                Some(lhs) => Expr::Mul(Arc::new(lhs), Arc::new(factor)),
+            });
+        }
+
+        result
+    }
+
+    pub fn to_expr(&self, name: &str) -> Expr {
+        let leading = if self.content.is_one() { None } else { Some(Expr::from(self.content.clone())) };
+
+        self.product(name, leading).unwrap_or_else(|| Expr::from(self.content.clone()))
+    }
+}
+
+impl fmt::Display for Factorization {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.product("x", None) {
+            Some(product) if !self.content.is_integer() => {
+                let content = Expr::from(self.content.clone());
+
+                match product {
+                    Expr::Add(_, _) | Expr::Sub(_, _) => write!(f, "({}) * ({})", content, product),
+                    _ => write!(f, "({}) * {}", content, product),
+                }
+            },
+            _ => write!(f, "{}", self.to_expr("x")),
+        }
+    }
+}
+
+impl Polynomial {
+    pub fn primitive(&self) -> (Rational, Polynomial) {
+        if self.is_zero() {
+            return (Rational::zero(), Polynomial::zero());
+        }
+
+        let denominator = self.coefficients().iter().fold(Integer::one(), |lcm, coefficient| {
+            let denominator = coefficient.denominator();
+
+            &(&lcm * denominator) / &lcm.gcd(denominator)
+        });
+
+        let numerator = self.coefficients().iter()
+            .filter(|coefficient| !coefficient.is_zero())
+            .fold(Integer::zero(), |gcd, coefficient| {
+                let numerator = &(coefficient.numerator() * &denominator) / coefficient.denominator();
+
+                gcd.gcd(&numerator)
+            });
+
+        let content = if self.leading_coefficient().is_negative() {
+            -Rational::new(numerator, denominator)
+        } else {
+            Rational::new(numerator, denominator)
+        };
+
+        let primitive = Polynomial::new(self.coefficients().iter().map(|coefficient| coefficient / &content).collect());
+
+        (content, primitive)
+    }
+
+    pub fn square_free_factorization(&self) -> Vec<(Polynomial, u32)> {
+        if self.is_constant() {
+            return Vec::new();
+        }
+
+        let derivative = self.derivative();
+        let common = self.gcd(&derivative);
+
+        let mut b = self / &common;
+        let mut d = &(&derivative / &common) - &b.derivative();
+        let mut multiplicity = 1;
+        let mut result = Vec::new();
+
+        while !b.is_constant() {
+            let factor = b.gcd(&d);
+
+            b = &b / &factor;
+            d = &(&d / &factor) - &b.derivative();
+
+            if !factor.is_constant() {
+                result.push((factor, multiplicity));
+            }
+
+            multiplicity += 1;
+        }
+
+        result
+    }
+
+    pub fn rational_roots(&self) -> Vec<Rational> {
+        let mut roots: Vec<Rational> = self.square_free_factorization()
+            .into_iter()
+            .flat_map(|(factor, _)| factor.square_free_rational_roots())
+            .collect();
+
+        roots.sort();
+        roots
+    }
+
+    fn square_free_rational_roots(&self) -> Vec<Rational> {
+        let (_, primitive) = self.primitive();
+        let leading = primitive.leading_coefficient().numerator().abs();
+
+        let precision = Rational::new(1, &(&leading * &leading) * &Integer::from(4));
+
+        self.isolate_real_roots()
+            .into_iter()
+            .filter_map(|interval| {
+                let (a, b) = self.refine_real_root(interval, &precision);
+                let candidate = (&(&a + &b) / &Rational::from(2)).limit_denominator(&leading);
+
+                if self.eval(&candidate).is_zero() {
+                    Some(candidate)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    pub fn factor(&self) -> Factorization {
+        let (content, _) = self.primitive();
+
+        let mut linear = Vec::new();
+        let mut factors = Vec::new();
+
+        for (factor, multiplicity) in self.square_free_factorization() {
+            let mut remainder = factor;
+
+            for root in remainder.square_free_rational_roots() {
+                let (_, linear_factor) = Polynomial::new(vec![-root.clone(), Rational::one()]).primitive();
+
+                remainder = &remainder / &linear_factor;
+                linear.push((root, linear_factor, multiplicity));
+            }
+
+            if !remainder.is_constant() {
+                factors.push((remainder.primitive().1, multiplicity));
+            }
+        }
+
+        linear.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut result: Vec<(Polynomial, u32)> = linear.into_iter()
+            .map(|(_, factor, multiplicity)| (factor, multiplicity))
+            .collect();
+
+        result.extend(factors);
+
+        Factorization {
+            content,
+            factors: result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::precise::algebra::polynomial::Polynomial;
+    use crate::precise::rational::Rational;
+
+    fn polynomial(coefficients: &[i128]) -> Polynomial {
+        Polynomial::new(coefficients.iter().map(|coefficient| Rational::from(*coefficient)).collect())
+    }
+
+    fn parse(source: &str) -> Polynomial {
+        Polynomial::from_expr(&source.parse().unwrap(), "x").unwrap()
+    }
+
+    #[test]
+    fn primitive() {
+        let (content, primitive) = parse("x ^ 2 / 2 - 3x / 4 + 1 / 4").primitive();
+
+        assert_eq!(content, Rational::new(1, 4));
+        assert_eq!(primitive, polynomial(&[1, -3, 2]));
+
+        let (content, primitive) = polynomial(&[6, -4]).primitive();
+
+        assert_eq!(content, Rational::from(-2));
+        assert_eq!(primitive, polynomial(&[-3, 2]));
+    }
+
+    #[test]
+    fn square_free_factorization() {
+        let factors = parse("(x - 1) ^ 3 * (x + 2) ^ 2 * (x ^ 2 + 1)").square_free_factorization();
+
+        assert_eq!(factors, vec![
+            (polynomial(&[1, 0, 1]), 1),
+            (polynomial(&[2, 1]), 2),
+            (polynomial(&[-1, 1]), 3),
+        ]);
+
+        assert!(polynomial(&[5]).square_free_factorization().is_empty());
+    }
+
+    #[test]
+    fn rational_roots() {
+        assert_eq!(parse("6x ^ 3 - 5x ^ 2 - 2x + 1").rational_roots(), vec![Rational::new(-1, 2), Rational::new(1, 3), Rational::one()]);
+        assert_eq!(parse("x ^ 2 - 2").rational_roots(), Vec::new());
+        assert_eq!(parse("(x - 1) ^ 2").rational_roots(), vec![Rational::one()]);
+    }
+
+    #[test]
+    fn factor() {
+        let result = parse("x ^ 3 - 6x ^ 2 + 11x - 6").factor();
+
+        assert_eq!(result.to_string(), "(x - 1) * (x - 2) * (x - 3)");
+        assert_eq!(result.factors().len(), 3);
+        assert_eq!(result.to_expr("x").to_string(), "(x - 1) * (x - 2) * (x - 3)");
+
+        let source = parse("-2 * (x + 1) ^ 2 * (x - 1) * (x ^ 2 - x - 1)");
+        let result = source.factor();
+
+        assert_eq!(result.to_string(), "-2 * (x + 1) ^ 2 * (x - 1) * (x ^ 2 - x - 1)");
+        assert_eq!(result.content(), &Rational::from(-2));
+        assert_eq!(result.expand(), source);
+
+        assert_eq!(parse("4x ^ 3 - x").factor().to_string(), "(2 * x + 1) * x * (2 * x - 1)");
+        assert_eq!(parse("x ^ 2 + 1").factor().to_string(), "x ^ 2 + 1");
+        assert_eq!(parse("x / 2 - 1 / 2").factor().to_string(), "(1 / 2) * (x - 1)");
+        assert_eq!(parse("-x ^ 2 - 1").factor().to_string(), "-(x ^ 2 + 1)");
+        assert_eq!(parse("(x ^ 2 + 1) / 3").factor().to_string(), "(1 / 3) * (x ^ 2 + 1)");
+        assert_eq!(parse("2x ^ 2 + 2").factor().to_string(), "2 * (x ^ 2 + 1)");
+        assert_eq!(polynomial(&[3]).factor().to_string(), "3");
+    }
+}
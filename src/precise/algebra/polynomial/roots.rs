@@ -0,0 +1,504 @@
+use std::cmp::Ordering;
+use std::fmt;
+use crate::performant::complex::number::Complex;
+use crate::precise::algebra::polynomial::Polynomial;
+use crate::precise::integer::Integer;
+use crate::precise::rational::{ self, Rational };
+
+const MAX_ABERTH_ITERATIONS: usize = 1000;
+const ABERTH_TOLERANCE: f64 = 1e-15;
+const MAX_POLISH_ITERATIONS: usize = 32;
+
+fn sign_changes(sequence: &[Polynomial], x: &Rational) -> usize {
+    let signs: Vec<bool> = sequence.iter()
+        .map(|polynomial| polynomial.eval(x))
+        .filter(|value| !value.is_zero())
+        .map(|value| value.is_negative())
+        .collect();
+
+    signs.windows(2).filter(|pair| pair[0] != pair[1]).count()
+}
+
+fn refine(sequence: &[Polynomial], interval: (Rational, Rational), precision: &Rational) -> (Rational, Rational) {
+    let (mut a, mut b) = interval;
+
+    if sequence[0].eval(&b).is_zero() {
+        return (b.clone(), b);
+    }
+
+    let two = Rational::from(2);
+    let changes = sign_changes(sequence, &a);
+
+    while &(&b - &a) > precision {
+        let m = &(&a + &b) / &two;
+
+        if sign_changes(sequence, &m) < changes {
+            if sequence[0].eval(&m).is_zero() {
+                return (m.clone(), m);
+            }
+
+            b = m;
+        } else {
+            a = m;
+        }
+    }
+
+    (a, b)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvergenceError {
+    estimate: Vec<Complex>,
+}
+
+impl ConvergenceError {
+    pub fn estimate(&self) -> &[Complex] {
+        &self.estimate
+    }
+}
+
+impl fmt::Display for ConvergenceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "complex root finding did not converge")
+    }
+}
+
+impl std::error::Error for ConvergenceError {}
+
+fn horner(coefficients: &[Complex], z: Complex) -> (Complex, Complex, f64) {
+    let mut value = Complex::from(0.0);
+    let mut derivative = Complex::from(0.0);
+    let mut magnitude = 0.0;
+
+    for coefficient in coefficients.iter().rev() {
+        derivative = derivative * z + value;
+        value = value * z + *coefficient;
+        magnitude = magnitude * z.norm() + coefficient.norm();
+    }
+
+    (value, derivative, magnitude)
+}
+
+fn fujiwara_bound(coefficients: &[f64]) -> f64 {
+    let degree = coefficients.len() - 1;
+
+    (1..=degree)
+        .map(|k| {
+            let coefficient = if k == degree { coefficients[0] / 2.0 } else { coefficients[degree - k] };
+
+            (coefficient.abs().ln() / k as f64).exp()
+        })
+        .fold(0.0, f64::max) * 2.0
+}
+
+fn aberth(coefficients: &[Complex]) -> Result<Vec<Complex>, Vec<Complex>> {
+    let degree = coefficients.len() - 1;
+
+    let mut roots: Vec<Complex> = (0..degree)
+        .map(|k| Complex::from_polar(1.0, 2.0 * std::f64::consts::PI * k as f64 / degree as f64 + 0.4))
+        .collect();
+    let mut done = vec![false; degree];
+
+    for _ in 0..MAX_ABERTH_ITERATIONS {
+        for k in 0..degree {
+            if done[k] {
+                continue;
+            }
+
+            let (value, derivative, magnitude) = horner(coefficients, roots[k]);
+
+            if value.norm() <= 4.0 * degree as f64 * f64::EPSILON * magnitude {
+                done[k] = true;
+                continue;
+            }
+
+            let ratio = value / derivative;
+            let repulsion = (0..degree)
+                .filter(|&j| j != k)
+                .fold(Complex::from(0.0), |sum, j| sum + Complex::from(1.0) / (roots[k] - roots[j]));
+
+            let step = ratio / (Complex::from(1.0) - ratio * repulsion);
+            roots[k] = roots[k] - step;
+
+            if step.norm() <= ABERTH_TOLERANCE * roots[k].norm().max(1.0) {
+                done[k] = true;
+            }
+        }
+
+        if done.iter().all(|done| *done) {
+            break;
+        }
+    }
+
+    let finite = roots.iter().all(|root| root.real.is_finite() && root.imaginary.is_finite());
+
+    if finite && done.iter().all(|done| *done) {
+        Ok(roots)
+    } else {
+        Err(roots)
+    }
+}
+
+fn dyadic(value: f64) -> Option<(Integer, u64)> {
+    let value = Rational::from_f64(value)?;
+
+    Some((value.numerator().clone(), value.denominator().bits() - 1))
+}
+
+fn gaussian_horner(coefficients: &[Integer], x: &Integer, y: &Integer, shift: u64) -> (Integer, Integer) {
+    let degree = coefficients.len() - 1;
+
+    coefficients.iter()
+        .enumerate()
+        .rev()
+        .fold((Integer::zero(), Integer::zero()), |(real, imaginary), (k, coefficient)| {
+            let next = &(&real * x) - &(&imaginary * y);
+
+            (next + (coefficient.clone() << (shift * (degree - k) as u64)), &(&real * y) + &(&imaginary * x))
+        })
+}
+
+fn quotient(numerator: &Integer, denominator: &Integer, exponent: i64) -> f64 {
+    let numerator_shift = numerator.bits().saturating_sub(64);
+    let denominator_shift = denominator.bits().saturating_sub(64);
+
+    let magnitude = (numerator.abs() >> numerator_shift).to_f64() / (denominator.abs() >> denominator_shift).to_f64();
+    let value = rational::scale_f64(magnitude, numerator_shift as i64 - denominator_shift as i64 + exponent);
+
+    if numerator.is_negative() != denominator.is_negative() {
+        -value
+    } else {
+        value
+    }
+}
+
+fn exact_ratio(coefficients: &[Integer], derivative: &[Integer], z: Complex) -> Option<Complex> {
+    let (x, x_shift) = dyadic(z.real)?;
+    let (y, y_shift) = dyadic(z.imaginary)?;
+    let shift = x_shift.max(y_shift);
+    let (x, y) = (x << (shift - x_shift), y << (shift - y_shift));
+
+    let value = gaussian_horner(coefficients, &x, &y, shift);
+    let slope = gaussian_horner(derivative, &x, &y, shift);
+
+    let norm = &(&slope.0 * &slope.0) + &(&slope.1 * &slope.1);
+    let real = &(&value.0 * &slope.0) + &(&value.1 * &slope.1);
+    let imaginary = &(&value.1 * &slope.0) - &(&value.0 * &slope.1);
+
+    if norm.is_zero() {
+        None
+    } else {
+        Some(Complex::new(quotient(&real, &norm, -(shift as i64)), quotient(&imaginary, &norm, -(shift as i64))))
+    }
+}
+
+fn polish(polynomial: &Polynomial, roots: &mut [Complex]) -> bool {
+    let degree = roots.len();
+    let coefficients: Vec<Integer> = polynomial.primitive().1.coefficients().iter().map(|coefficient| coefficient.numerator().clone()).collect();
+    let derivative: Vec<Integer> = coefficients.iter()
+        .enumerate()
+        .skip(1)
+        .map(|(k, coefficient)| coefficient * &Integer::from(k))
+        .collect();
+
+    for _ in 0..MAX_POLISH_ITERATIONS {
+        let mut converged = true;
+
+        for k in 0..degree {
+            let ratio = match exact_ratio(&coefficients, &derivative, roots[k]) {
+                Some(ratio) => ratio,
+                None => return false,
+            };
+
+            let repulsion = (0..degree)
+                .filter(|&j| j != k)
+                .fold(Complex::from(0.0), |sum, j| sum + Complex::from(1.0) / (roots[k] - roots[j]));
+
+            let step = ratio / (Complex::from(1.0) - ratio * repulsion);
+            roots[k] = roots[k] - step;
+
+            if step.norm() > ABERTH_TOLERANCE * roots[k].norm().max(1.0) || !step.norm().is_finite() {
+                converged = false;
+            }
+        }
+
+        if converged {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn factor_roots(factor: &Polynomial) -> Result<Vec<Complex>, Vec<Complex>> {
+    let monic: Vec<f64> = factor.monic().coefficients().iter().map(Rational::to_f64).collect();
+    let degree = monic.len() - 1;
+    let scale = fujiwara_bound(&monic);
+
+    if !scale.is_finite() || scale == 0.0 {
+        return Err(vec![Complex::new(f64::NAN, f64::NAN); degree]);
+    }
+
+    let scaled: Vec<Complex> = monic.iter()
+        .enumerate()
+        .map(|(k, coefficient)| {
+            let magnitude = (coefficient.abs().ln() - (degree - k) as f64 * scale.ln()).exp();
+
+            Complex::from(magnitude.copysign(*coefficient))
+        })
+        .collect();
+
+    let unscale = |roots: Vec<Complex>| -> Vec<Complex> { roots.into_iter().map(|root| root * Complex::from(scale)).collect() };
+
+    let mut roots = aberth(&scaled).map(unscale).map_err(unscale)?;
+
+    if polish(factor, &mut roots) {
+        Ok(roots)
+    } else {
+        Err(roots)
+    }
+}
+
+impl Polynomial {
+    pub fn sturm_sequence(&self) -> Vec<Polynomial> {
+        if self.is_zero() {
+            return Vec::new();
+        }
+
+        let square_free = self / &self.gcd(&self.derivative());
+        let derivative = square_free.derivative();
+        let mut sequence = vec![square_free, derivative];
+
+        loop {
+            let length = sequence.len();
+
+            if sequence[length - 1].is_zero() {
+                sequence.pop();
+                break;
+            }
+
+            let remainder = -(&sequence[length - 2] % &sequence[length - 1]);
+
+            if remainder.is_zero() {
+                break;
+            }
+
+            sequence.push(remainder);
+        }
+
+        sequence
+    }
+
+    pub fn root_bound(&self) -> Rational {
+        let leading = self.leading_coefficient().abs();
+        let degree = self.degree().unwrap_or(0);
+
+        self.coefficients()[..degree].iter()
+            .map(|coefficient| &coefficient.abs() / &leading)
+            .fold(Rational::zero(), Rational::max) + Rational::one()
+    }
+
+    pub fn count_real_roots(&self) -> usize {
+        match self.degree() {
+            None | Some(0) => 0,
+            _ => {
+                let sequence = self.sturm_sequence();
+                let bound = self.root_bound();
+
+                sign_changes(&sequence, &-&bound) - sign_changes(&sequence, &bound)
+            },
+        }
+    }
+
+    pub fn isolate_real_roots(&self) -> Vec<(Rational, Rational)> {
+        if self.degree().unwrap_or(0) == 0 {
+            return Vec::new();
+        }
+
+        let sequence = self.sturm_sequence();
+        let bound = self.root_bound();
+        let two = Rational::from(2);
+
+        let mut result = Vec::new();
+        let mut pending = vec![(-&bound, bound)];
+
+        while let Some((a, b)) = pending.pop() {
+            let count = sign_changes(&sequence, &a) - sign_changes(&sequence, &b);
+
+            match count {
+                0 => (),
+                1 => result.push((a, b)),
+                _ => {
+                    let m = &(&a + &b) / &two;
+
+                    pending.push((a, m.clone()));
+                    pending.push((m, b));
+                },
+            }
+        }
+
+        result.sort();
+        result
+    }
+
+    pub fn real_roots(&self, precision: &Rational) -> Vec<Rational> {
+        let sequence = self.sturm_sequence();
+        let two = Rational::from(2);
+
+        self.isolate_real_roots()
+            .into_iter()
+            .map(|interval| {
+                let (a, b) = refine(&sequence, interval, precision);
+
+                &(&a + &b) / &two
+            })
+            .collect()
+    }
+
+    pub(crate) fn refine_real_root(&self, interval: (Rational, Rational), precision: &Rational) -> (Rational, Rational) {
+        refine(&self.sturm_sequence(), interval, precision)
+    }
+
+    pub fn complex_roots(&self) -> Result<Vec<Complex>, ConvergenceError> {
+        let mut roots = Vec::new();
+        let mut converged = true;
+
+        for (factor, multiplicity) in self.square_free_factorization() {
+            let factor_roots = match factor.degree() {
+                Some(1) => vec![Complex::from((-&factor.coefficient(0) / factor.coefficient(1)).to_f64())],
+                Some(_) => factor_roots(&factor).unwrap_or_else(|estimate| {
+                    converged = false;
+                    estimate
+                }),
+                None => Vec::new(),
+            };
+
+            for root in factor_roots {
+                let threshold = ABERTH_TOLERANCE * 1e3 * root.norm().max(1.0);
+                let clean = |part: f64| if part.abs() <= threshold { 0.0 } else { part };
+                let root = Complex::new(clean(root.real), clean(root.imaginary));
+
+                for _ in 0..multiplicity {
+                    roots.push(root);
+                }
+            }
+        }
+
+        roots.sort_by(|a, b| {
+            a.real.partial_cmp(&b.real)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| a.imaginary.partial_cmp(&b.imaginary).unwrap_or(Ordering::Equal))
+        });
+
+        if converged {
+            Ok(roots)
+        } else {
+            Err(ConvergenceError {
+                estimate: roots,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::precise::algebra::polynomial::Polynomial;
+    use crate::precise::rational::Rational;
+
+    fn polynomial(coefficients: &[i128]) -> Polynomial {
+        Polynomial::new(coefficients.iter().map(|coefficient| Rational::from(*coefficient)).collect())
+    }
+
+    #[test]
+    fn count_real_roots() {
+        assert_eq!(polynomial(&[-6, 11, -6, 1]).count_real_roots(), 3);
+        assert_eq!(polynomial(&[1, 0, 1]).count_real_roots(), 0);
+        assert_eq!(polynomial(&[1, 2, 1]).count_real_roots(), 1);
+        assert_eq!(polynomial(&[-2, 0, 0, 0, 0, 1]).count_real_roots(), 1);
+    }
+
+    #[test]
+    fn isolate_real_roots() {
+        let intervals = polynomial(&[-6, 11, -6, 1]).isolate_real_roots();
+
+        assert_eq!(intervals.len(), 3);
+
+        for (interval, root) in intervals.iter().zip([1, 2, 3].iter()) {
+            let root = Rational::from(*root);
+
+            assert!(interval.0 < root && root <= interval.1);
+        }
+    }
+
+    #[test]
+    fn real_roots() {
+        let precision = Rational::new(1, 1_000_000_000_000i64);
+        let roots = polynomial(&[-2, 0, 1]).real_roots(&precision);
+
+        assert_eq!(roots.len(), 2);
+        assert!((roots[0].to_f64() + std::f64::consts::SQRT_2).abs() < 1e-12);
+        assert!((roots[1].to_f64() - std::f64::consts::SQRT_2).abs() < 1e-12);
+
+        let roots = polynomial(&[-6, 11, -6, 1]).real_roots(&precision);
+
+        for (root, expected) in roots.iter().zip([1, 2, 3].iter()) {
+            assert!((root - &Rational::from(*expected)).abs() <= precision);
+        }
+
+        let fine = Rational::new(1, 1_000_000_000_000_000_000_000_000_000_000i128);
+        let root = &polynomial(&[-2, 0, 0, 1]).real_roots(&fine)[0];
+        let error = &(&(root * root) * root) - &Rational::from(2);
+
+        assert!(error.abs() < Rational::new(1, 1_000_000_000_000_000_000_000_000_000i128));
+    }
+
+    #[test]
+    fn complex_roots() {
+        let roots = polynomial(&[1, 0, 1]).complex_roots().unwrap();
+
+        assert_eq!(roots.len(), 2);
+        assert!(roots[0].real.abs() < 1e-12 && (roots[0].imaginary + 1.0).abs() < 1e-12);
+        assert!(roots[1].real.abs() < 1e-12 && (roots[1].imaginary - 1.0).abs() < 1e-12);
+
+        let roots = polynomial(&[-1, 0, 0, 0, 0, 1]).complex_roots().unwrap();
+
+        assert_eq!(roots.len(), 5);
+
+        for root in roots.iter() {
+            assert!((root.norm() - 1.0).abs() < 1e-12);
+        }
+
+        let roots = polynomial(&[-4, 4, -1, 1]).compose(&polynomial(&[0, 1])).complex_roots().unwrap();
+        assert_eq!(roots.len(), 3);
+        assert_eq!(roots.iter().filter(|root| root.imaginary == 0.0).count(), 1);
+
+        let roots = polynomial(&[1, 2, 1]).complex_roots().unwrap();
+        assert_eq!(roots, vec![roots[0]; 2]);
+        assert_eq!(roots[0].real, -1.0);
+    }
+
+    #[test]
+    fn wilkinson() {
+        let wilkinson = (1..=20).fold(polynomial(&[1]), |product, k| &product * &polynomial(&[-k, 1]));
+        let roots = wilkinson.complex_roots().unwrap();
+
+        assert_eq!(roots.len(), 20);
+
+        for (root, expected) in roots.iter().zip(1..=20) {
+            assert_eq!(root.imaginary, 0.0);
+            assert!((root.real - expected as f64).abs() < 1e-12, "{:?} != {}", root, expected);
+        }
+
+        let shifted = &wilkinson + &polynomial(&[1_000_000_000_000_000_000]);
+        let roots = shifted.complex_roots().unwrap();
+
+        assert_eq!(roots.len(), 20);
+
+        for root in roots.iter() {
+            assert!(root.real.is_finite() && root.imaginary.is_finite());
+
+            let residual = shifted.eval_f64(root.real).abs();
+            assert!(root.imaginary != 0.0 || residual < 1e6, "{:?}", root);
+        }
+    }
+}
@@ -0,0 +1,433 @@
+use std::fmt;
+use std::convert::TryFrom;
+use std::str::FromStr;
+use serde_derive::{ Serialize, Deserialize };
+use crate::precise::expression::{ Expr, EvalErrorKind };
+use crate::precise::expression::constant::Constant;
+use crate::precise::expression::function::Function;
+use crate::precise::expression::parse::{ self, ParseError };
+use crate::precise::expression::simplify::as_number;
+use crate::precise::expression::structural::Structural;
+use crate::precise::trace::{ Rule, Trace };
+
+const TOLERANCE: f64 = 1e-9;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Equation {
+    pub lhs: Expr,
+    pub rhs: Expr,
+}
+
+#[derive(Debug, Clone)]
+pub enum Solution {
+    Values(Vec<Expr>),
+    All,
+    Unsolved,
+}
+
+fn is_zero(expression: &Expr) -> bool {
+    matches!(expression, Expr::Val(x) if x.is_zero())
+}
+
+fn occurrences(expression: &Expr, name: &str) -> usize {
+    match expression {
+        Expr::Val(_) | Expr::Const(_) => 0,
+        Expr::Var(x) => if x == name { 1 } else { 0 },
+        Expr::Add(x, y) | Expr::Sub(x, y) | Expr::Mul(x, y) | Expr::Div(x, y) | Expr::Rem(x, y) | Expr::Exp(x, y) => {
+            occurrences(x, name) + occurrences(y, name)
+        },
+        Expr::Fun(_, arguments) => arguments.iter().map(|argument| occurrences(argument, name)).sum(),
+    }
+}
+
+fn call(function: Function, arguments: Vec<Expr>) -> Expr {
+    Expr::Fun(function, arguments.into())
+}
+
+fn quadratic_coefficients(expression: &Expr, name: &str) -> Option<[Expr; 3]> {
+    let first = expression.derivative(name);
+    let second = first.derivative(name);
+
+    if !is_zero(&second.derivative(name)) {
+        return None;
+    }
+
+    let coefficients = [
+        expression.substitute(name, 0).simplify(),
+        first.substitute(name, 0).simplify(),
+        (second.substitute(name, 0) / Expr::from(2)).simplify(),
+    ];
+
+    let x = Expr::Var(name.to_string());
+    let polynomial = coefficients[0].clone() + coefficients[1].clone() * x.clone() + coefficients[2].clone() * (x ^ Expr::from(2));
+
+    if is_zero(&(expression.clone() - polynomial).simplify()) {
+        Some(coefficients)
+    } else {
+        None
+    }
+}
+
+fn quadratic(coefficients: [Expr; 3]) -> (Rule, Vec<Expr>) {
+    let [c, b, a] = coefficients;
+
+    if is_zero(&a) {
+        return (Rule::LinearFormula, vec![Expr::from(-1) * c / b]);
+    }
+
+    let discriminant = (b.clone() * b.clone() - Expr::from(4) * a.clone() * c).simplify();
+    let denominator = Expr::from(2) * a;
+
+    let roots = match as_number(&discriminant) {
+        Some(value) if value.is_negative() => Vec::new(),
+        Some(value) if value.is_zero() => vec![Expr::from(-1) * b / denominator],
+        _ => {
+            let root = discriminant ^ (Expr::from(1) / Expr::from(2));
+
+            vec![
+                (Expr::from(-1) * b.clone() - root.clone()) / denominator.clone(),
+                (Expr::from(-1) * b + root) / denominator,
+            ]
+        },
+    };
+
+    (Rule::QuadraticFormula, roots)
+}
+
+fn roots(target: Expr, exponent: &Expr) -> Vec<Expr> {
+    let exponent = match as_number(exponent) {
+        Some(exponent) => exponent,
+        None => return vec![target ^ (Expr::from(1) / exponent.clone())],
+    };
+
+    let root = target.clone() ^ Expr::from(exponent.recip());
+
+    if !exponent.numerator().is_even() {
+        return vec![root];
+    }
+
+    match as_number(&target) {
+        Some(value) if value.is_negative() => Vec::new(),
+        Some(value) if value.is_zero() => vec![root],
+        _ => vec![Expr::from(-1) * root.clone(), root],
+    }
+}
+
+fn isolate(expression: &Expr, target: Expr, name: &str, trace: &mut Trace) -> Option<Vec<Expr>> {
+    if matches!(expression, Expr::Var(x) if x == name) {
+        return Some(vec![target]);
+    }
+
+    let before = Equation::new(expression, target.clone());
+
+    let candidates = match expression {
+        Expr::Add(x, y) if x.contains_var(name) => vec![(Rule::SubtractFromBothSides, &**x, target - (**y).clone())],
+        Expr::Add(x, y) => vec![(Rule::SubtractFromBothSides, &**y, target - (**x).clone())],
+        Expr::Sub(x, y) if x.contains_var(name) => vec![(Rule::AddToBothSides, &**x, target + (**y).clone())],
+        Expr::Sub(x, y) => vec![(Rule::AddToBothSides, &**y, (**x).clone() - target)],
+        Expr::Mul(x, y) if x.contains_var(name) => vec![(Rule::DivideBothSides, &**x, target / (**y).clone())],
+        Expr::Mul(x, y) => vec![(Rule::DivideBothSides, &**y, target / (**x).clone())],
+        Expr::Div(x, y) if x.contains_var(name) => vec![(Rule::MultiplyBothSides, &**x, target * (**y).clone())],
+        Expr::Div(x, y) => vec![(Rule::MultiplyBothSides, &**y, (**x).clone() / target)],
+        Expr::Exp(x, y) if x.contains_var(name) => {
+            let rule = match as_number(y) {
+                Some(exponent) if !exponent.is_integer() && exponent.numerator().is_one() => Rule::RaiseToPower,
+                _ => Rule::TakeRoot,
+            };
+
+            roots(target, y).into_iter().map(|target| (rule, &**x, target)).collect()
+        },
+        Expr::Exp(x, y) => match &**x {
+            Expr::Const(Constant::E) => vec![(Rule::TakeLogarithm, &**y, call(Function::Ln, vec![target]))],
+            _ => vec![(Rule::TakeLogarithm, &**y, call(Function::Log, vec![(**x).clone(), target]))],
+        },
+        Expr::Fun(Function::Log, arguments) if arguments[1].contains_var(name) => {
+            vec![(Rule::Exponentiate, &arguments[1], arguments[0].clone() ^ target)]
+        },
+        Expr::Fun(Function::Log, arguments) => vec![(Rule::Exponentiate, &arguments[0], arguments[1].clone() ^ (Expr::from(1) / target))],
+        Expr::Fun(function, arguments) => {
+            let argument = &arguments[0];
+
+            match function {
+                Function::Ln => vec![(Rule::Exponentiate, argument, Expr::e() ^ target)],
+                Function::Exp => vec![(Rule::TakeLogarithm, argument, call(Function::Ln, vec![target]))],
+                Function::Sqrt => vec![(Rule::RaiseToPower, argument, target ^ Expr::from(2))],
+                Function::Abs => vec![
+                    (Rule::SplitAbsoluteValue, argument, target.clone()),
+                    (Rule::SplitAbsoluteValue, argument, Expr::from(-1) * target.clone()),
+                ],
+                _ => return None,
+            }
+        },
+        _ => return None,
+    };
+
+    let mut result = Vec::new();
+
+    for (rule, isolated, next) in candidates {
+        let next = next.simplify();
+
+        trace.record(rule, before.clone(), Equation::new(isolated, next.clone()));
+        result.extend(isolate(isolated, next, name, trace)?);
+    }
+
+    Some(result)
+}
+
+fn satisfies(difference: &Expr, name: &str, value: &Expr) -> bool {
+    match value.try_eval() {
+        Err(error) => return matches!(error.kind(), EvalErrorKind::UnboundVariable(_)),
+        Ok(value) if !value.is_finite() => return false,
+        _ => (),
+    }
+
+    match difference.substitute(name, value.clone()).try_eval() {
+        Ok(residual) => residual.abs() <= TOLERANCE * value.eval().abs().max(1.0),
+        Err(error) => matches!(error.kind(), EvalErrorKind::UnboundVariable(_)),
+    }
+}
+
+impl Equation {
+    pub fn new<L: Into<Expr>, R: Into<Expr>>(lhs: L, rhs: R) -> Equation {
+        Equation {
+            lhs: lhs.into(),
+            rhs: rhs.into(),
+        }
+    }
+
+    pub fn solve(&self, name: &str) -> Solution {
+        self.solve_traced(name).0
+    }
+
+    pub fn solve_traced(&self, name: &str) -> (Solution, Trace) {
+        let mut trace = Trace::new();
+        let difference = (self.lhs.clone() - self.rhs.clone()).simplify();
+
+        if !(is_zero(&self.rhs) && self.lhs.structural_eq(&difference)) {
+            trace.record(Rule::MoveTermsToOneSide, self.clone(), Equation::new(difference.clone(), 0));
+        }
+
+        if !difference.contains_var(name) {
+            let solution = match as_number(&difference) {
+                Some(value) if value.is_zero() => Solution::All,
+                Some(_) => Solution::Values(Vec::new()),
+                None => Solution::Unsolved,
+            };
+
+            return (solution, trace);
+        }
+
+        let candidates = match quadratic_coefficients(&difference, name) {
+            Some(coefficients) => {
+                let (rule, candidates) = quadratic(coefficients);
+                let candidates: Vec<Expr> = candidates.into_iter().map(|candidate| candidate.simplify()).collect();
+
+                for candidate in candidates.iter() {
+                    trace.record(rule, Equation::new(difference.clone(), 0), Equation::new(Expr::Var(name.to_string()), candidate.clone()));
+                }
+
+                candidates
+            },
+            None if occurrences(&difference, name) == 1 => match isolate(&difference, Expr::from(0), name, &mut trace) {
+                Some(candidates) => candidates,
+                None => return (Solution::Unsolved, trace),
+            },
+            None => return (Solution::Unsolved, trace),
+        };
+
+        let mut solutions: Vec<Expr> = Vec::new();
+
+        for candidate in candidates {
+            let candidate = candidate.simplify();
+
+            if solutions.iter().any(|solution| solution.structural_eq(&candidate)) {
+                continue;
+            }
+
+            if satisfies(&difference, name, &candidate) {
+                solutions.push(candidate);
+            } else {
+                let substituted = Equation::new(self.lhs.substitute(name, candidate.clone()), self.rhs.substitute(name, candidate.clone()));
+
+                trace.record(Rule::RejectExtraneous, Equation::new(Expr::Var(name.to_string()), candidate), substituted);
+            }
+        }
+
+        solutions.sort_by(|lhs, rhs| lhs.partial_cmp(rhs).unwrap_or_else(|| Structural(lhs.clone()).cmp(&Structural(rhs.clone()))));
+
+        (Solution::Values(solutions), trace)
+    }
+
+    pub fn to_latex(&self) -> String {
+        format!("{} = {}", self.lhs.to_latex(), self.rhs.to_latex())
+    }
+}
+
+impl PartialEq for Equation {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.lhs.structural_eq(&rhs.lhs) && self.rhs.structural_eq(&rhs.rhs)
+    }
+}
+
+impl Eq for Equation {}
+
+impl PartialEq for Solution {
+    fn eq(&self, rhs: &Self) -> bool {
+        match (self, rhs) {
+            (Solution::Values(lhs), Solution::Values(rhs)) => {
+                lhs.len() == rhs.len() && lhs.iter().zip(rhs.iter()).all(|(lhs, rhs)| lhs.structural_eq(rhs))
+            },
+            (Solution::All, Solution::All) | (Solution::Unsolved, Solution::Unsolved) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Solution {}
+
+impl fmt::Display for Equation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} = {}", self.lhs, self.rhs)
+    }
+}
+
+impl FromStr for Equation {
+    type Err = ParseError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let (lhs, rhs) = parse::parse_equation(source)?;
+
+        Ok(Equation {
+            lhs,
+            rhs,
+        })
+    }
+}
+
+impl TryFrom<&str> for Equation {
+    type Error = ParseError;
+
+    fn try_from(source: &str) -> Result<Self, Self::Error> {
+        source.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ Equation, Solution };
+    use crate::precise::expression::Expr;
+    use crate::precise::trace::Rule;
+
+    fn solve(source: &str) -> Solution {
+        source.parse::<Equation>().unwrap().solve("x")
+    }
+
+    fn values(source: &str) -> Vec<String> {
+        match solve(source) {
+            Solution::Values(values) => values.iter().map(|value| value.to_string()).collect(),
+            solution => panic!("{} solved as {:?}", source, solution),
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let equation: Equation = "2x + 1 = x - 3".parse().unwrap();
+
+        assert_eq!(equation.to_string(), "2 * x + 1 = x - 3");
+        assert!("x + 1".parse::<Equation>().is_err());
+        assert!("x = 1 = 2".parse::<Equation>().is_err());
+        assert!("x = 1".parse::<Expr>().is_err());
+    }
+
+    #[test]
+    fn linear() {
+        assert_eq!(values("2x + 1 = x - 3"), vec!["-4"]);
+        assert_eq!(values("3(x - 1) / 4 = 2"), vec!["11 / 3"]);
+        assert_eq!(values("a * x + b = 0"), vec!["-b / a"]);
+    }
+
+    #[test]
+    fn quadratic() {
+        assert_eq!(values("x ^ 2 - 5x + 6 = 0"), vec!["2", "3"]);
+        assert_eq!(values("x ^ 2 = 2"), vec!["-2 ^ (1 / 2)", "2 ^ (1 / 2)"]);
+        assert_eq!(values("x ^ 2 + 2x + 1 = 0"), vec!["-1"]);
+        assert_eq!(values("x ^ 2 + 1 = 0"), Vec::<String>::new());
+        assert_eq!(values("x ^ 2 + 2x = 1"), vec!["-1 - 2 ^ (1 / 2)", "-1 + 2 ^ (1 / 2)"]);
+    }
+
+    #[test]
+    fn powers_logarithms_and_exponentials() {
+        assert_eq!(values("x ^ 3 = 8"), vec!["2"]);
+        assert_eq!(values("2 ^ x = 32"), vec!["5"]);
+        assert_eq!(values("exp(2x) = 5"), vec!["ln(5) / 2"]);
+        assert_eq!(values("ln(x - 1) = 0"), vec!["2"]);
+        assert_eq!(values("log(3, x) = 2"), vec!["9"]);
+        assert_eq!(values("sqrt(x + 1) = 3"), vec!["8"]);
+        assert_eq!(values("sqrt(x) = -2"), Vec::<String>::new());
+        assert_eq!(values("abs(x - 2) = 3"), vec!["-1", "5"]);
+        assert_eq!(values("1 / x = 4"), vec!["1 / 4"]);
+    }
+
+    #[test]
+    fn degenerate() {
+        assert_eq!(solve("x + 1 = 1 + x"), Solution::All);
+        assert_eq!(solve("x = x + 1"), Solution::Values(Vec::new()));
+    }
+
+    #[test]
+    fn solve_traced() {
+        let equation: Equation = "2x + 1 = 7".parse().unwrap();
+        let (solution, trace) = equation.solve_traced("x");
+
+        assert_eq!(solution, Solution::Values(vec![Expr::from(3)]));
+        assert_eq!(trace.to_string(), [
+            "1. 2 * x + 1 = 7 => 2 * x - 6 = 0 (move all terms to one side)",
+            "2. 2 * x - 6 = 0 => x = 3 (solve linear equation)",
+        ].join("\n"));
+
+        let equation: Equation = "sqrt(x) + 1 = 0".parse().unwrap();
+        let (solution, trace) = equation.solve_traced("x");
+        let rules: Vec<Rule> = trace.steps().iter().map(|step| step.rule()).collect();
+
+        assert_eq!(solution, Solution::Values(Vec::new()));
+        assert_eq!(rules, vec![Rule::MoveTermsToOneSide, Rule::SubtractFromBothSides, Rule::RaiseToPower, Rule::RejectExtraneous]);
+        assert_eq!(trace.steps()[2].to_string(), "x ^ (1 / 2) = -1 => x = 1 (raise both sides to a power)");
+        assert_eq!(trace.steps()[3].after().to_string(), "sqrt(1) + 1 = 0");
+    }
+
+    #[test]
+    fn to_latex() {
+        let equation: Equation = "x ^ 2 = 1 / 2".parse().unwrap();
+
+        assert_eq!(equation.to_latex(), "x^{2} = \\frac{1}{2}");
+    }
+
+    #[test]
+    fn unsolved() {
+        assert_eq!(solve("sin(x) = x"), Solution::Unsolved);
+        assert_eq!(solve("x ^ 5 + x = 1"), Solution::Unsolved);
+        assert_eq!(solve("floor(x) = 2"), Solution::Unsolved);
+    }
+
+    #[test]
+    fn structural_equality() {
+        let equation: Equation = "x + 1 = 2".parse().unwrap();
+
+        assert_eq!(equation, "x + 1 = 2".parse().unwrap());
+        assert_ne!(equation, "1 + x = 2".parse().unwrap());
+        assert_ne!(Equation::new(Expr::from(2), 0), Equation::new(Expr::from(1) + Expr::from(1), 0));
+        assert_ne!(Solution::Values(vec![Expr::from(4)]), Solution::Values(vec![Expr::from(2) * Expr::from(2)]));
+        assert_ne!(Solution::Values(vec![Expr::from(1)]), Solution::Values(vec![Expr::from(1), Expr::from(1)]));
+        assert_eq!(Solution::Unsolved, Solution::Unsolved);
+    }
+
+    #[test]
+    fn reserved_names() {
+        let equation = Equation::new(Expr::Var(String::from("e")) ^ Expr::from(2), 4);
+        let (solution, trace) = equation.solve_traced("e");
+
+        assert_eq!(solution, Solution::Values(vec![Expr::from(-2), Expr::from(2)]));
+        assert_eq!(trace.steps().len(), 3);
+        assert_eq!("x ^ 2 = 4".parse::<Equation>().unwrap().solve("pi"), Solution::Unsolved);
+    }
+}
@@ -1,150 +1,479 @@
 use std::fmt;
 use std::cmp::Ordering;
 use std::ops::{ Add, Sub, Mul, Div, Rem, BitXor };
-use serde_derive::{ Serialize, Deserialize };
-use crate::utils::gcd;
+use std::sync::Arc;
+use crate::precise::integer::Integer;
+use crate::precise::rational::Rational;
+use self::constant::Constant;
+use self::environment::Environment;
+use self::function::{ Function, FunctionError };
+use self::print::PrintOptions;
+
+pub mod batch;
+pub mod compile;
+pub mod constant;
+pub mod derivative;
+pub mod environment;
+pub mod function;
+pub mod integral;
+pub mod latex;
+pub mod parse;
+pub mod pool;
+pub mod print;
+pub mod quadrature;
+pub mod roots;
+pub mod serialize;
+pub mod simplify;
+pub mod structural;
+pub mod traverse;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Expr {
-    Val(i128),
-    Add(Box<Expr>, Box<Expr>),
-    Sub(Box<Expr>, Box<Expr>),
-    Mul(Box<Expr>, Box<Expr>),
-    Div(Box<Expr>, Box<Expr>),
-    Rem(Box<Expr>, Box<Expr>),
-    Exp(Box<Expr>, Box<Expr>),
+    Val(Integer),
+    Var(String),
+    Const(Constant),
+    Add(Arc<Expr>, Arc<Expr>),
+    Sub(Arc<Expr>, Arc<Expr>),
+    Mul(Arc<Expr>, Arc<Expr>),
+    Div(Arc<Expr>, Arc<Expr>),
+    Rem(Arc<Expr>, Arc<Expr>),
+    Exp(Arc<Expr>, Arc<Expr>),
+    Fun(Function, Arc<[Expr]>),
 }
 
 impl Expr {
+    pub fn var<N: Into<String>>(name: N) -> Expr {
+        let name = name.into();
+        assert!(Constant::from_name(&name).is_none(), "`{}` is reserved for a constant", name);
+
+        Expr::Var(name)
+    }
+
+    pub fn approximate_f64(value: f64, max_denominator: u64) -> Expr {
+        match Rational::approximate_f64(value, &Integer::from(max_denominator.max(1))) {
+            Some(value) => Expr::from(value),
+            None => non_finite(value),
+        }
+    }
+
+    pub fn pi() -> Expr {
+        Expr::Const(Constant::Pi)
+    }
+
+    pub fn e() -> Expr {
+        Expr::Const(Constant::E)
+    }
+
+    pub fn i() -> Expr {
+        Expr::Const(Constant::I)
+    }
+
+    pub fn call(function: Function, arguments: Vec<Expr>) -> Expr {
+        assert_eq!(arguments.len(), function.arity(), "wrong number of arguments to `{}`", function);
+
+        Expr::Fun(function, arguments.into())
+    }
+
+    pub fn contains_var(&self, name: &str) -> bool {
+        match self {
+            Expr::Val(_) | Expr::Const(_) => false,
+            Expr::Var(x) => x == name,
+            Expr::Add(x, y) | Expr::Sub(x, y) | Expr::Mul(x, y) | Expr::Div(x, y) | Expr::Rem(x, y) | Expr::Exp(x, y) => {
+                x.contains_var(name) || y.contains_var(name)
+            },
+            Expr::Fun(_, arguments) => arguments.iter().any(|argument| argument.contains_var(name)),
+        }
+    }
+
+    pub fn substitute<V: Into<Expr>>(&self, name: &str, value: V) -> Expr {
+        let value = value.into();
+        let mut built: Vec<Expr> = Vec::new();
+
+        self.postorder::<(), _>(|expression| {
+            let result = match expression {
+                Expr::Var(x) if x == name => value.clone(),
+                Expr::Val(_) | Expr::Var(_) | Expr::Const(_) => expression.clone(),
+                Expr::Fun(function, arguments) => Expr::Fun(*function, built.split_off(built.len() - arguments.len()).into()),
+                _ => {
+                    let y = Arc::new(built.pop().unwrap());
+                    let x = Arc::new(built.pop().unwrap());
+
+                    match expression {
+                        Expr::Add(_, _) => Expr::Add(x, y),
+                        Expr::Sub(_, _) => Expr::Sub(x, y),
+                        Expr::Mul(_, _) => Expr::Mul(x, y),
+                        Expr::Div(_, _) => Expr::Div(x, y),
+                        Expr::Rem(_, _) => Expr::Rem(x, y),
+                        _ => Expr::Exp(x, y),
+                    }
+                },
+            };
+
+            built.push(result);
+            Ok(())
+        }).unwrap();
+
+        built.pop().unwrap()
+    }
+
     pub fn eval(&self) -> f64 {
+        self.eval_float(&Environment::new(), false).unwrap_or(f64::NAN)
+    }
+
+    pub fn try_eval(&self) -> Result<f64, EvalError> {
+        self.eval_with(&Environment::new())
+    }
+
+    pub fn eval_with(&self, environment: &Environment) -> Result<f64, EvalError> {
+        self.eval_float(environment, true)
+    }
+
+    fn eval_float(&self, environment: &Environment, checked: bool) -> Result<f64, EvalError> {
+        let mut values = Vec::new();
+
+        self.postorder(|expression| {
+            let start = values.len() - expression.arity();
+            let value = expression.eval_float_node(&values[start..], environment, checked)?;
+
+            values.truncate(start);
+            values.push(value);
+            Ok(())
+        })?;
+
+        Ok(values.pop().unwrap())
+    }
+
+    pub(crate) fn arity(&self) -> usize {
+        match self {
+            Expr::Val(_) | Expr::Var(_) | Expr::Const(_) => 0,
+            Expr::Add(_, _) | Expr::Sub(_, _) | Expr::Mul(_, _) | Expr::Div(_, _) | Expr::Rem(_, _) | Expr::Exp(_, _) => 2,
+            Expr::Fun(_, arguments) => arguments.len(),
+        }
+    }
+
+    pub(crate) fn eval_float_node(&self, operands: &[f64], environment: &Environment, checked: bool) -> Result<f64, EvalError> {
+        let (lhs, rhs) = match self {
+            Expr::Val(x) => {
+                let value = x.to_f64();
+
+                if checked && value.is_infinite() {
+                    return Err(EvalError::new(EvalErrorKind::Overflow, self));
+                }
+
+                return Ok(value);
+            },
+            Expr::Var(x) => return match environment.get(x) {
+                Some(value) => value.eval_float(&Environment::new(), checked),
+                None => Err(EvalError::new(EvalErrorKind::UnboundVariable(x.clone()), self)),
+            },
+            Expr::Const(x) => return match x.value() {
+                Some(value) => Ok(value),
+                None if !checked => Ok(f64::NAN),
+                None => Err(EvalError::new(EvalErrorKind::DomainError, self)),
+            },
+            Expr::Add(_, _) | Expr::Sub(_, _) | Expr::Mul(_, _) | Expr::Div(_, _) | Expr::Rem(_, _) | Expr::Exp(_, _) => {
+                (operands[0], operands[1])
+            },
+            Expr::Fun(function, _) => {
+                let value = match function.eval(operands) {
+                    Ok(value) => value,
+                    Err(_) if !checked => f64::NAN,
+                    Err(error) => return Err(EvalError::from_function(error, self)),
+                };
+
+                if checked && !value.is_finite() {
+                    return Err(EvalError::new(EvalErrorKind::Overflow, self));
+                }
+
+                return Ok(value);
+            },
+        };
+
+        if checked {
+            match self {
+                Expr::Div(_, _) | Expr::Rem(_, _) if rhs == 0.0 => {
+                    return Err(EvalError::new(EvalErrorKind::DivisionByZero, self));
+                },
+                Expr::Exp(_, _) if lhs == 0.0 && rhs < 0.0 => {
+                    return Err(EvalError::new(EvalErrorKind::DivisionByZero, self));
+                },
+                Expr::Exp(_, _) if lhs < 0.0 && rhs.fract() != 0.0 => {
+                    return Err(EvalError::new(EvalErrorKind::DomainError, self));
+                },
+                _ => {},
+            }
+        }
+
+        let value = match self {
+            Expr::Add(_, _) => lhs + rhs,
+            Expr::Sub(_, _) => lhs - rhs,
+            Expr::Mul(_, _) => lhs * rhs,
+            Expr::Div(_, _) => lhs / rhs,
+            Expr::Rem(_, _) => lhs % rhs,
+            _ => lhs.powf(rhs),
+        };
+
+        if checked && !value.is_finite() {
+            return Err(EvalError::new(EvalErrorKind::Overflow, self));
+        }
+
+        Ok(value)
+    }
+
+    pub fn eval_exact(&self) -> Result<Rational, EvalError> {
+        self.eval_exact_with(&Environment::new())
+    }
+
+    pub fn eval_exact_with(&self, environment: &Environment) -> Result<Rational, EvalError> {
+        let mut values = Vec::new();
+
+        self.postorder(|expression| {
+            let value = expression.eval_exact_node(&mut values, environment)?;
+            values.push(value);
+            Ok(())
+        })?;
+
+        Ok(values.pop().unwrap())
+    }
+
+    fn eval_exact_node(&self, values: &mut Vec<Rational>, environment: &Environment) -> Result<Rational, EvalError> {
+        let (lhs, rhs) = match self {
+            Expr::Val(x) => return Ok(Rational::from(x.clone())),
+            Expr::Var(x) => return match environment.get(x) {
+                Some(value) => value.eval_exact(),
+                None => Err(EvalError::new(EvalErrorKind::UnboundVariable(x.clone()), self)),
+            },
+            Expr::Const(Constant::I) => return Err(EvalError::new(EvalErrorKind::DomainError, self)),
+            Expr::Const(_) => return Err(EvalError::new(EvalErrorKind::Irrational, self)),
+            Expr::Fun(function, arguments) => {
+                let arguments = values.split_off(values.len() - arguments.len());
+
+                return function.eval_exact(&arguments).map_err(|error| EvalError::from_function(error, self));
+            },
+            _ => {
+                let rhs = values.pop().unwrap();
+                let lhs = values.pop().unwrap();
+
+                (lhs, rhs)
+            },
+        };
+
+        match self {
+            Expr::Add(_, _) => Ok(lhs + rhs),
+            Expr::Sub(_, _) => Ok(lhs - rhs),
+            Expr::Mul(_, _) => Ok(lhs * rhs),
+            Expr::Div(_, _) | Expr::Rem(_, _) if rhs.is_zero() => Err(EvalError::new(EvalErrorKind::DivisionByZero, self)),
+            Expr::Div(_, _) => Ok(lhs / rhs),
+            Expr::Rem(_, _) => Ok(lhs % rhs),
+            _ => {
+                let (base, exponent) = (lhs, rhs);
+
+                if base.is_zero() && exponent.is_negative() {
+                    return Err(EvalError::new(EvalErrorKind::DivisionByZero, self));
+                }
+
+                if base.is_negative() && exponent.denominator().is_even() {
+                    return Err(EvalError::new(EvalErrorKind::DomainError, self));
+                }
+
+                base.root(exponent.denominator())
+                    .ok_or_else(|| EvalError::new(EvalErrorKind::Irrational, self))?
+                    .checked_pow(exponent.numerator())
+                    .ok_or_else(|| EvalError::new(EvalErrorKind::Overflow, self))
+            },
+        }
+    }
+
+    pub fn cmp_exact(&self, rhs: &Expr) -> Result<Ordering, EvalError> {
+        Ok(self.eval_exact()?.cmp(&rhs.eval_exact()?))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalErrorKind {
+    WrongArity,
+    DivisionByZero,
+    DomainError,
+    Irrational,
+    Overflow,
+    UnboundVariable(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct EvalError {
+    kind: EvalErrorKind,
+    expression: Expr,
+}
+
+impl EvalError {
+    pub fn new(kind: EvalErrorKind, expression: &Expr) -> EvalError {
+        EvalError {
+            kind,
+            expression: expression.clone(),
+        }
+    }
+
+    fn from_function(error: FunctionError, expression: &Expr) -> EvalError {
+        let kind = match error {
+            FunctionError::Arity => EvalErrorKind::WrongArity,
+            FunctionError::DomainError => EvalErrorKind::DomainError,
+            FunctionError::Irrational => EvalErrorKind::Irrational,
+        };
+
+        EvalError::new(kind, expression)
+    }
+
+    pub fn kind(&self) -> &EvalErrorKind {
+        &self.kind
+    }
+
+    pub fn expression(&self) -> &Expr {
+        &self.expression
+    }
+}
+
+impl PartialEq for EvalError {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.kind == rhs.kind && self.expression.structural_eq(&rhs.expression)
+    }
+}
+
+impl Eq for EvalError {}
+
+impl fmt::Display for EvalErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Expr::Val(x) => f64::from((*x) as i32),
-            Expr::Add(x, y) => x.eval() + y.eval(),
-            Expr::Sub(x, y) => x.eval() - y.eval(),
-            Expr::Mul(x, y) => x.eval() * y.eval(),
-            Expr::Div(x, y) => x.eval() / y.eval(),
-            Expr::Rem(x, y) => x.eval() % y.eval(),
-            Expr::Exp(x, y) => x.eval().powf(y.eval()),
+            EvalErrorKind::WrongArity => write!(f, "wrong number of arguments"),
+            EvalErrorKind::DivisionByZero => write!(f, "division by zero"),
+            EvalErrorKind::DomainError => write!(f, "argument outside of the domain"),
+            EvalErrorKind::Irrational => write!(f, "result is not a rational number"),
+            EvalErrorKind::Overflow => write!(f, "result is too large to represent"),
+            EvalErrorKind::UnboundVariable(name) => write!(f, "unbound variable `{}`", name),
         }
     }
 }
 
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} in `{}`", self.kind, self.expression)
+    }
+}
+
+impl std::error::Error for EvalError {}
+
 impl From <i8> for Expr {
     fn from(value: i8) -> Self {
-        Expr::Val(value.into())
+        Expr::Val(Integer::from(value))
     }
 }
 
 impl From<i16> for Expr {
     fn from(value: i16) -> Self {
-        Expr::Val(value.into())
+        Expr::Val(Integer::from(value))
     }
 }
 
 impl From<i32> for Expr {
     fn from(value: i32) -> Self {
-        Expr::Val(value.into())
+        Expr::Val(Integer::from(value))
     }
 }
 
 impl From<i64> for Expr {
     fn from(value: i64) -> Self {
-        Expr::Val(value.into())
+        Expr::Val(Integer::from(value))
     }
 }
 
 impl From<i128> for Expr {
     fn from(value: i128) -> Self {
-        Expr::Val(value.into())
+        Expr::Val(Integer::from(value))
     }
 }
 
 impl From <u8> for Expr {
     fn from(value: u8) -> Self {
-        Expr::Val(value.into())
+        Expr::Val(Integer::from(value))
     }
 }
 
 impl From<u16> for Expr {
     fn from(value: u16) -> Self {
-        Expr::Val(value.into())
+        Expr::Val(Integer::from(value))
     }
 }
 
 impl From<u32> for Expr {
     fn from(value: u32) -> Self {
-        Expr::Val(value.into())
+        Expr::Val(Integer::from(value))
     }
 }
 
 impl From<u64> for Expr {
     fn from(value: u64) -> Self {
-        Expr::Val(value.into())
+        Expr::Val(Integer::from(value))
     }
 }
 
 impl From<u128> for Expr {
     fn from(value: u128) -> Self {
-        Expr::Val(value as i128)
+        Expr::Val(Integer::from(value))
     }
 }
 
 impl From<isize> for Expr {
     fn from(value: isize) -> Self {
-        Expr::Val(value as i128)
+        Expr::Val(Integer::from(value))
     }
 }
 
 impl From<usize> for Expr {
     fn from(value: usize) -> Self {
-        Expr::Val(value as i128)
+        Expr::Val(Integer::from(value))
     }
 }
 
 impl From<f32> for Expr {
     fn from(value: f32) -> Self {
-        let denominator = 10_000_000;
-        let numerator = (value * (denominator as f32)) as i128;
-
-        let gcd = gcd(numerator, denominator);
-
-        let numerator = numerator / gcd;
-        let denominator = denominator / gcd;
-
-        if denominator == 1 {
-            Expr::from(numerator)
-        } else {
-            Expr::Div(
-                Box::new(
-                    Expr::from(numerator / gcd)
-                ),
-                Box::new(
-                    Expr::from(denominator / gcd)
-                )
-            )
-        }
+        Expr::from(f64::from(value))
     }
 }
 
 impl From<f64> for Expr {
     fn from(value: f64) -> Self {
-        let denominator = 10_000_000_000;
-        let numerator = (value * (denominator as f64)) as i128;
+        match Rational::from_f64(value) {
+            Some(value) => Expr::from(value),
+            None => non_finite(value),
+        }
+    }
+}
 
-        let gcd = gcd(numerator, denominator);
+fn non_finite(value: f64) -> Expr {
+    let numerator = if value.is_nan() {
+        0
+    } else {
+        value.signum() as i128
+    };
+
+    Expr::Div(Arc::new(Expr::from(numerator)), Arc::new(Expr::from(0)))
+}
 
-        let numerator = numerator / gcd;
-        let denominator = denominator / gcd;
+impl From<Integer> for Expr {
+    fn from(value: Integer) -> Self {
+        Expr::Val(value)
+    }
+}
 
-        if denominator == 1 {
-            Expr::from(numerator)
+impl From<Rational> for Expr {
+    fn from(value: Rational) -> Self {
+        if value.is_integer() {
+            Expr::from(value.numerator().clone())
         } else {
             Expr::Div(
-                Box::new(
-                    Expr::from(numerator / gcd)
+                Arc::new(
+                    Expr::from(value.numerator().clone())
                 ),
-                Box::new(
-                    Expr::from(denominator / gcd)
+                Arc::new(
+                    Expr::from(value.denominator().clone())
                 )
             )
         }
@@ -246,10 +575,10 @@ impl Add for Expr {
 
     fn add(self, rhs: Self) -> Self::Output {
         Expr::Add(
-            Box::new(
+            Arc::new(
                 self
             ),
-            Box::new(
+            Arc::new(
                 Expr::from(rhs)
             )
         )
@@ -464,1320 +793,2687 @@ impl Add<Expr> for f64 {
     }
 }
 
-impl Sub for Expr {
-    type Output = Self;
+impl<'a> Add<&'a Expr> for &'a Expr {
+    type Output = Expr;
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        Expr::Sub(
-            Box::new(
-                self
-            ),
-            Box::new(
-                Expr::from(rhs)
-            )
-        )
+    fn add(self, rhs: &'a Expr) -> Self::Output {
+        self.clone() + rhs.clone()
     }
 }
 
-impl Sub<i8> for Expr {
-    type Output = Self;
+impl Add<Expr> for &Expr {
+    type Output = Expr;
 
-    fn sub(self, rhs: i8) -> Self::Output {
-        self - Expr::from(rhs)
+    fn add(self, rhs: Expr) -> Self::Output {
+        self.clone() + rhs
     }
 }
 
-impl Sub<i16> for Expr {
-    type Output = Self;
+impl Add<&Expr> for Expr {
+    type Output = Expr;
 
-    fn sub(self, rhs: i16) -> Self::Output {
-        self - Expr::from(rhs)
+    fn add(self, rhs: &Expr) -> Self::Output {
+        self + rhs.clone()
     }
 }
 
-impl Sub<i32> for Expr {
-    type Output = Self;
+impl Add<i8> for &Expr {
+    type Output = Expr;
 
-    fn sub(self, rhs: i32) -> Self::Output {
-        self - Expr::from(rhs)
+    fn add(self, rhs: i8) -> Self::Output {
+        self.clone() + Expr::from(rhs)
     }
 }
 
-impl Sub<i64> for Expr {
-    type Output = Self;
+impl Add<i16> for &Expr {
+    type Output = Expr;
 
-    fn sub(self, rhs: i64) -> Self::Output {
-        self - Expr::from(rhs)
+    fn add(self, rhs: i16) -> Self::Output {
+        self.clone() + Expr::from(rhs)
     }
 }
 
-impl Sub<i128> for Expr {
-    type Output = Self;
+impl Add<i32> for &Expr {
+    type Output = Expr;
 
-    fn sub(self, rhs: i128) -> Self::Output {
-        self - Expr::from(rhs)
+    fn add(self, rhs: i32) -> Self::Output {
+        self.clone() + Expr::from(rhs)
     }
 }
 
-impl Sub<u8> for Expr {
-    type Output = Self;
+impl Add<i64> for &Expr {
+    type Output = Expr;
 
-    fn sub(self, rhs: u8) -> Self::Output {
-        self - Expr::from(rhs)
+    fn add(self, rhs: i64) -> Self::Output {
+        self.clone() + Expr::from(rhs)
     }
 }
 
-impl Sub<u16> for Expr {
-    type Output = Self;
+impl Add<i128> for &Expr {
+    type Output = Expr;
 
-    fn sub(self, rhs: u16) -> Self::Output {
-        self - Expr::from(rhs)
+    fn add(self, rhs: i128) -> Self::Output {
+        self.clone() + Expr::from(rhs)
     }
 }
 
-impl Sub<u32> for Expr {
-    type Output = Self;
+impl Add<u8> for &Expr {
+    type Output = Expr;
 
-    fn sub(self, rhs: u32) -> Self::Output {
-        self - Expr::from(rhs)
+    fn add(self, rhs: u8) -> Self::Output {
+        self.clone() + Expr::from(rhs)
     }
 }
 
-impl Sub<u64> for Expr {
-    type Output = Self;
+impl Add<u16> for &Expr {
+    type Output = Expr;
 
-    fn sub(self, rhs: u64) -> Self::Output {
-        self - Expr::from(rhs)
+    fn add(self, rhs: u16) -> Self::Output {
+        self.clone() + Expr::from(rhs)
     }
 }
 
-impl Sub<u128> for Expr {
-    type Output = Self;
+impl Add<u32> for &Expr {
+    type Output = Expr;
 
-    fn sub(self, rhs: u128) -> Self::Output {
-        self - Expr::from(rhs)
+    fn add(self, rhs: u32) -> Self::Output {
+        self.clone() + Expr::from(rhs)
     }
 }
 
-impl Sub<isize> for Expr {
-    type Output = Self;
+impl Add<u64> for &Expr {
+    type Output = Expr;
 
-    fn sub(self, rhs: isize) -> Self::Output {
-        self - Expr::from(rhs)
+    fn add(self, rhs: u64) -> Self::Output {
+        self.clone() + Expr::from(rhs)
     }
 }
 
-impl Sub<usize> for Expr {
-    type Output = Self;
+impl Add<u128> for &Expr {
+    type Output = Expr;
 
-    fn sub(self, rhs: usize) -> Self::Output {
-        self - Expr::from(rhs)
+    fn add(self, rhs: u128) -> Self::Output {
+        self.clone() + Expr::from(rhs)
     }
 }
 
-impl Sub<f32> for Expr {
-    type Output = Self;
+impl Add<isize> for &Expr {
+    type Output = Expr;
 
-    fn sub(self, rhs: f32) -> Self::Output {
-        self - Expr::from(rhs)
+    fn add(self, rhs: isize) -> Self::Output {
+        self.clone() + Expr::from(rhs)
     }
 }
 
-impl Sub<f64> for Expr {
-    type Output = Self;
+impl Add<usize> for &Expr {
+    type Output = Expr;
 
-    fn sub(self, rhs: f64) -> Self::Output {
-        self - Expr::from(rhs)
+    fn add(self, rhs: usize) -> Self::Output {
+        self.clone() + Expr::from(rhs)
     }
 }
 
-impl Sub<Expr> for i8 {
+impl Add<f32> for &Expr {
     type Output = Expr;
 
-    fn sub(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) - rhs
+    fn add(self, rhs: f32) -> Self::Output {
+        self.clone() + Expr::from(rhs)
     }
 }
 
-impl Sub<Expr> for i16 {
+impl Add<f64> for &Expr {
     type Output = Expr;
 
-    fn sub(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) - rhs
+    fn add(self, rhs: f64) -> Self::Output {
+        self.clone() + Expr::from(rhs)
     }
 }
 
-impl Sub<Expr> for i32 {
+impl Add<&Expr> for i8 {
     type Output = Expr;
 
-    fn sub(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) - rhs
+    fn add(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) + rhs.clone()
     }
 }
 
-impl Sub<Expr> for i64 {
+impl Add<&Expr> for i16 {
     type Output = Expr;
 
-    fn sub(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) - rhs
+    fn add(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) + rhs.clone()
     }
 }
 
-impl Sub<Expr> for i128 {
+impl Add<&Expr> for i32 {
     type Output = Expr;
 
-    fn sub(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) - rhs
+    fn add(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) + rhs.clone()
     }
 }
 
-impl Sub<Expr> for u8 {
+impl Add<&Expr> for i64 {
     type Output = Expr;
 
-    fn sub(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) - rhs
+    fn add(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) + rhs.clone()
     }
 }
 
-impl Sub<Expr> for u16 {
+impl Add<&Expr> for i128 {
     type Output = Expr;
 
-    fn sub(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) - rhs
+    fn add(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) + rhs.clone()
     }
 }
 
-impl Sub<Expr> for u32 {
+impl Add<&Expr> for u8 {
     type Output = Expr;
 
-    fn sub(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) - rhs
+    fn add(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) + rhs.clone()
     }
 }
 
-impl Sub<Expr> for u64 {
+impl Add<&Expr> for u16 {
     type Output = Expr;
 
-    fn sub(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) - rhs
+    fn add(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) + rhs.clone()
     }
 }
 
-impl Sub<Expr> for u128 {
+impl Add<&Expr> for u32 {
     type Output = Expr;
 
-    fn sub(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) - rhs
+    fn add(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) + rhs.clone()
     }
 }
 
-impl Sub<Expr> for f32 {
+impl Add<&Expr> for u64 {
     type Output = Expr;
 
-    fn sub(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) - rhs
+    fn add(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) + rhs.clone()
     }
 }
 
-impl Sub<Expr> for f64 {
+impl Add<&Expr> for u128 {
     type Output = Expr;
 
-    fn sub(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) - rhs
+    fn add(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) + rhs.clone()
     }
 }
 
-impl Mul for Expr {
-    type Output = Self;
+impl Add<&Expr> for f32 {
+    type Output = Expr;
 
-    fn mul(self, rhs: Self) -> Self::Output {
-        let rhs = Expr::from(rhs);
+    fn add(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) + rhs.clone()
+    }
+}
 
-        if let Expr::Val(x) = self {
-            if x == 1 {
-                return rhs;
-            } else if x == 0 {
-                return Expr::from(0)
-            }
-        }
+impl Add<&Expr> for f64 {
+    type Output = Expr;
+
+    fn add(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) + rhs.clone()
+    }
+}
+
+impl Sub for Expr {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Expr::Sub(
+            Arc::new(
+                self
+            ),
+            Arc::new(
+                Expr::from(rhs)
+            )
+        )
+    }
+}
+
+impl Sub<i8> for Expr {
+    type Output = Self;
+
+    fn sub(self, rhs: i8) -> Self::Output {
+        self - Expr::from(rhs)
+    }
+}
+
+impl Sub<i16> for Expr {
+    type Output = Self;
+
+    fn sub(self, rhs: i16) -> Self::Output {
+        self - Expr::from(rhs)
+    }
+}
+
+impl Sub<i32> for Expr {
+    type Output = Self;
+
+    fn sub(self, rhs: i32) -> Self::Output {
+        self - Expr::from(rhs)
+    }
+}
+
+impl Sub<i64> for Expr {
+    type Output = Self;
+
+    fn sub(self, rhs: i64) -> Self::Output {
+        self - Expr::from(rhs)
+    }
+}
+
+impl Sub<i128> for Expr {
+    type Output = Self;
+
+    fn sub(self, rhs: i128) -> Self::Output {
+        self - Expr::from(rhs)
+    }
+}
+
+impl Sub<u8> for Expr {
+    type Output = Self;
+
+    fn sub(self, rhs: u8) -> Self::Output {
+        self - Expr::from(rhs)
+    }
+}
+
+impl Sub<u16> for Expr {
+    type Output = Self;
+
+    fn sub(self, rhs: u16) -> Self::Output {
+        self - Expr::from(rhs)
+    }
+}
+
+impl Sub<u32> for Expr {
+    type Output = Self;
+
+    fn sub(self, rhs: u32) -> Self::Output {
+        self - Expr::from(rhs)
+    }
+}
+
+impl Sub<u64> for Expr {
+    type Output = Self;
+
+    fn sub(self, rhs: u64) -> Self::Output {
+        self - Expr::from(rhs)
+    }
+}
+
+impl Sub<u128> for Expr {
+    type Output = Self;
+
+    fn sub(self, rhs: u128) -> Self::Output {
+        self - Expr::from(rhs)
+    }
+}
+
+impl Sub<isize> for Expr {
+    type Output = Self;
+
+    fn sub(self, rhs: isize) -> Self::Output {
+        self - Expr::from(rhs)
+    }
+}
+
+impl Sub<usize> for Expr {
+    type Output = Self;
+
+    fn sub(self, rhs: usize) -> Self::Output {
+        self - Expr::from(rhs)
+    }
+}
+
+impl Sub<f32> for Expr {
+    type Output = Self;
+
+    fn sub(self, rhs: f32) -> Self::Output {
+        self - Expr::from(rhs)
+    }
+}
+
+impl Sub<f64> for Expr {
+    type Output = Self;
+
+    fn sub(self, rhs: f64) -> Self::Output {
+        self - Expr::from(rhs)
+    }
+}
+
+impl Sub<Expr> for i8 {
+    type Output = Expr;
+
+    fn sub(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) - rhs
+    }
+}
+
+impl Sub<Expr> for i16 {
+    type Output = Expr;
+
+    fn sub(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) - rhs
+    }
+}
+
+impl Sub<Expr> for i32 {
+    type Output = Expr;
+
+    fn sub(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) - rhs
+    }
+}
+
+impl Sub<Expr> for i64 {
+    type Output = Expr;
+
+    fn sub(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) - rhs
+    }
+}
+
+impl Sub<Expr> for i128 {
+    type Output = Expr;
+
+    fn sub(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) - rhs
+    }
+}
+
+impl Sub<Expr> for u8 {
+    type Output = Expr;
+
+    fn sub(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) - rhs
+    }
+}
+
+impl Sub<Expr> for u16 {
+    type Output = Expr;
+
+    fn sub(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) - rhs
+    }
+}
+
+impl Sub<Expr> for u32 {
+    type Output = Expr;
+
+    fn sub(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) - rhs
+    }
+}
+
+impl Sub<Expr> for u64 {
+    type Output = Expr;
+
+    fn sub(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) - rhs
+    }
+}
+
+impl Sub<Expr> for u128 {
+    type Output = Expr;
+
+    fn sub(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) - rhs
+    }
+}
+
+impl Sub<Expr> for f32 {
+    type Output = Expr;
+
+    fn sub(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) - rhs
+    }
+}
+
+impl Sub<Expr> for f64 {
+    type Output = Expr;
+
+    fn sub(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) - rhs
+    }
+}
+
+impl<'a> Sub<&'a Expr> for &'a Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: &'a Expr) -> Self::Output {
+        self.clone() - rhs.clone()
+    }
+}
+
+impl Sub<Expr> for &Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: Expr) -> Self::Output {
+        self.clone() - rhs
+    }
+}
+
+impl Sub<&Expr> for Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: &Expr) -> Self::Output {
+        self - rhs.clone()
+    }
+}
+
+impl Sub<i8> for &Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: i8) -> Self::Output {
+        self.clone() - Expr::from(rhs)
+    }
+}
+
+impl Sub<i16> for &Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: i16) -> Self::Output {
+        self.clone() - Expr::from(rhs)
+    }
+}
+
+impl Sub<i32> for &Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: i32) -> Self::Output {
+        self.clone() - Expr::from(rhs)
+    }
+}
+
+impl Sub<i64> for &Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: i64) -> Self::Output {
+        self.clone() - Expr::from(rhs)
+    }
+}
+
+impl Sub<i128> for &Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: i128) -> Self::Output {
+        self.clone() - Expr::from(rhs)
+    }
+}
+
+impl Sub<u8> for &Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: u8) -> Self::Output {
+        self.clone() - Expr::from(rhs)
+    }
+}
+
+impl Sub<u16> for &Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: u16) -> Self::Output {
+        self.clone() - Expr::from(rhs)
+    }
+}
+
+impl Sub<u32> for &Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: u32) -> Self::Output {
+        self.clone() - Expr::from(rhs)
+    }
+}
+
+impl Sub<u64> for &Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: u64) -> Self::Output {
+        self.clone() - Expr::from(rhs)
+    }
+}
+
+impl Sub<u128> for &Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: u128) -> Self::Output {
+        self.clone() - Expr::from(rhs)
+    }
+}
+
+impl Sub<isize> for &Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: isize) -> Self::Output {
+        self.clone() - Expr::from(rhs)
+    }
+}
+
+impl Sub<usize> for &Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: usize) -> Self::Output {
+        self.clone() - Expr::from(rhs)
+    }
+}
+
+impl Sub<f32> for &Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: f32) -> Self::Output {
+        self.clone() - Expr::from(rhs)
+    }
+}
+
+impl Sub<f64> for &Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: f64) -> Self::Output {
+        self.clone() - Expr::from(rhs)
+    }
+}
+
+impl Sub<&Expr> for i8 {
+    type Output = Expr;
+
+    fn sub(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) - rhs.clone()
+    }
+}
+
+impl Sub<&Expr> for i16 {
+    type Output = Expr;
+
+    fn sub(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) - rhs.clone()
+    }
+}
+
+impl Sub<&Expr> for i32 {
+    type Output = Expr;
+
+    fn sub(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) - rhs.clone()
+    }
+}
+
+impl Sub<&Expr> for i64 {
+    type Output = Expr;
+
+    fn sub(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) - rhs.clone()
+    }
+}
+
+impl Sub<&Expr> for i128 {
+    type Output = Expr;
+
+    fn sub(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) - rhs.clone()
+    }
+}
+
+impl Sub<&Expr> for u8 {
+    type Output = Expr;
+
+    fn sub(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) - rhs.clone()
+    }
+}
+
+impl Sub<&Expr> for u16 {
+    type Output = Expr;
+
+    fn sub(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) - rhs.clone()
+    }
+}
+
+impl Sub<&Expr> for u32 {
+    type Output = Expr;
+
+    fn sub(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) - rhs.clone()
+    }
+}
+
+impl Sub<&Expr> for u64 {
+    type Output = Expr;
+
+    fn sub(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) - rhs.clone()
+    }
+}
+
+impl Sub<&Expr> for u128 {
+    type Output = Expr;
+
+    fn sub(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) - rhs.clone()
+    }
+}
+
+impl Sub<&Expr> for f32 {
+    type Output = Expr;
+
+    fn sub(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) - rhs.clone()
+    }
+}
+
+impl Sub<&Expr> for f64 {
+    type Output = Expr;
+
+    fn sub(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) - rhs.clone()
+    }
+}
+
+impl Mul for Expr {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let rhs = Expr::from(rhs);
+
+        if let Expr::Val(x) = &self {
+            if x.is_one() {
+                return rhs;
+            } else if x.is_zero() {
+                return Expr::from(0)
+            }
+        }
+
+        if let Expr::Val(x) = &rhs {
+            if x.is_one() {
+                return self;
+            } else if x.is_zero() {
+                return Expr::from(0)
+            }
+        }
+
+        Expr::Mul(
+            Arc::new(
+                self
+            ),
+            Arc::new(
+                rhs
+            )
+        )
+    }
+}
+
+impl Mul<i8> for Expr {
+    type Output = Self;
+
+    fn mul(self, rhs: i8) -> Self::Output {
+        self * Expr::from(rhs)
+    }
+}
+
+impl Mul<i16> for Expr {
+    type Output = Self;
+
+    fn mul(self, rhs: i16) -> Self::Output {
+        self * Expr::from(rhs)
+    }
+}
+
+impl Mul<i32> for Expr {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        self * Expr::from(rhs)
+    }
+}
+
+impl Mul<i64> for Expr {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        self * Expr::from(rhs)
+    }
+}
+
+impl Mul<i128> for Expr {
+    type Output = Self;
+
+    fn mul(self, rhs: i128) -> Self::Output {
+        self * Expr::from(rhs)
+    }
+}
+
+impl Mul<u8> for Expr {
+    type Output = Self;
+
+    fn mul(self, rhs: u8) -> Self::Output {
+        self * Expr::from(rhs)
+    }
+}
+
+impl Mul<u16> for Expr {
+    type Output = Self;
+
+    fn mul(self, rhs: u16) -> Self::Output {
+        self * Expr::from(rhs)
+    }
+}
+
+impl Mul<u32> for Expr {
+    type Output = Self;
+
+    fn mul(self, rhs: u32) -> Self::Output {
+        self * Expr::from(rhs)
+    }
+}
+
+impl Mul<u64> for Expr {
+    type Output = Self;
+
+    fn mul(self, rhs: u64) -> Self::Output {
+        self * Expr::from(rhs)
+    }
+}
+
+impl Mul<u128> for Expr {
+    type Output = Self;
+
+    fn mul(self, rhs: u128) -> Self::Output {
+        self * Expr::from(rhs)
+    }
+}
+
+impl Mul<isize> for Expr {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        self * Expr::from(rhs)
+    }
+}
+
+impl Mul<usize> for Expr {
+    type Output = Self;
+
+    fn mul(self, rhs: usize) -> Self::Output {
+        self * Expr::from(rhs)
+    }
+}
+
+impl Mul<f32> for Expr {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * Expr::from(rhs)
+    }
+}
+
+impl Mul<f64> for Expr {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Expr::from(rhs)
+    }
+}
+
+impl Mul<Expr> for i8 {
+    type Output = Expr;
+
+    fn mul(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) * rhs
+    }
+}
+
+impl Mul<Expr> for i16 {
+    type Output = Expr;
+
+    fn mul(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) * rhs
+    }
+}
+
+impl Mul<Expr> for i32 {
+    type Output = Expr;
+
+    fn mul(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) * rhs
+    }
+}
+
+impl Mul<Expr> for i64 {
+    type Output = Expr;
+
+    fn mul(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) * rhs
+    }
+}
+
+impl Mul<Expr> for i128 {
+    type Output = Expr;
+
+    fn mul(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) * rhs
+    }
+}
+
+impl Mul<Expr> for u8 {
+    type Output = Expr;
+
+    fn mul(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) * rhs
+    }
+}
+
+impl Mul<Expr> for u16 {
+    type Output = Expr;
+
+    fn mul(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) * rhs
+    }
+}
+
+impl Mul<Expr> for u32 {
+    type Output = Expr;
+
+    fn mul(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) * rhs
+    }
+}
+
+impl Mul<Expr> for u64 {
+    type Output = Expr;
+
+    fn mul(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) * rhs
+    }
+}
+
+impl Mul<Expr> for u128 {
+    type Output = Expr;
+
+    fn mul(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) * rhs
+    }
+}
+
+impl Mul<Expr> for f32 {
+    type Output = Expr;
+
+    fn mul(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) * rhs
+    }
+}
+
+impl Mul<Expr> for f64 {
+    type Output = Expr;
+
+    fn mul(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) * rhs
+    }
+}
+
+impl<'a> Mul<&'a Expr> for &'a Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: &'a Expr) -> Self::Output {
+        self.clone() * rhs.clone()
+    }
+}
+
+impl Mul<Expr> for &Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: Expr) -> Self::Output {
+        self.clone() * rhs
+    }
+}
+
+impl Mul<&Expr> for Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: &Expr) -> Self::Output {
+        self * rhs.clone()
+    }
+}
+
+impl Mul<i8> for &Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: i8) -> Self::Output {
+        self.clone() * Expr::from(rhs)
+    }
+}
+
+impl Mul<i16> for &Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: i16) -> Self::Output {
+        self.clone() * Expr::from(rhs)
+    }
+}
+
+impl Mul<i32> for &Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        self.clone() * Expr::from(rhs)
+    }
+}
+
+impl Mul<i64> for &Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        self.clone() * Expr::from(rhs)
+    }
+}
+
+impl Mul<i128> for &Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: i128) -> Self::Output {
+        self.clone() * Expr::from(rhs)
+    }
+}
+
+impl Mul<u8> for &Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: u8) -> Self::Output {
+        self.clone() * Expr::from(rhs)
+    }
+}
+
+impl Mul<u16> for &Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: u16) -> Self::Output {
+        self.clone() * Expr::from(rhs)
+    }
+}
+
+impl Mul<u32> for &Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: u32) -> Self::Output {
+        self.clone() * Expr::from(rhs)
+    }
+}
+
+impl Mul<u64> for &Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: u64) -> Self::Output {
+        self.clone() * Expr::from(rhs)
+    }
+}
+
+impl Mul<u128> for &Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: u128) -> Self::Output {
+        self.clone() * Expr::from(rhs)
+    }
+}
+
+impl Mul<isize> for &Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        self.clone() * Expr::from(rhs)
+    }
+}
+
+impl Mul<usize> for &Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: usize) -> Self::Output {
+        self.clone() * Expr::from(rhs)
+    }
+}
+
+impl Mul<f32> for &Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self.clone() * Expr::from(rhs)
+    }
+}
+
+impl Mul<f64> for &Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.clone() * Expr::from(rhs)
+    }
+}
+
+impl Mul<&Expr> for i8 {
+    type Output = Expr;
+
+    fn mul(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) * rhs.clone()
+    }
+}
+
+impl Mul<&Expr> for i16 {
+    type Output = Expr;
+
+    fn mul(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) * rhs.clone()
+    }
+}
+
+impl Mul<&Expr> for i32 {
+    type Output = Expr;
+
+    fn mul(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) * rhs.clone()
+    }
+}
+
+impl Mul<&Expr> for i64 {
+    type Output = Expr;
+
+    fn mul(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) * rhs.clone()
+    }
+}
+
+impl Mul<&Expr> for i128 {
+    type Output = Expr;
+
+    fn mul(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) * rhs.clone()
+    }
+}
+
+impl Mul<&Expr> for u8 {
+    type Output = Expr;
+
+    fn mul(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) * rhs.clone()
+    }
+}
+
+impl Mul<&Expr> for u16 {
+    type Output = Expr;
+
+    fn mul(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) * rhs.clone()
+    }
+}
+
+impl Mul<&Expr> for u32 {
+    type Output = Expr;
+
+    fn mul(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) * rhs.clone()
+    }
+}
+
+impl Mul<&Expr> for u64 {
+    type Output = Expr;
+
+    fn mul(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) * rhs.clone()
+    }
+}
+
+impl Mul<&Expr> for u128 {
+    type Output = Expr;
+
+    fn mul(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) * rhs.clone()
+    }
+}
+
+impl Mul<&Expr> for f32 {
+    type Output = Expr;
+
+    fn mul(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) * rhs.clone()
+    }
+}
+
+impl Mul<&Expr> for f64 {
+    type Output = Expr;
+
+    fn mul(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) * rhs.clone()
+    }
+}
+
+impl Div for Expr {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let rhs = Expr::from(rhs);
+
+        if let Expr::Val(x) = &rhs {
+            if x.is_one() {
+                return self;
+            } else if !x.is_zero() {
+                if let Expr::Val(y) = &self {
+                    let gcd = x.gcd(y);
+
+                    return Expr::Div(
+                        Arc::new(
+                            Expr::from(y / &gcd)
+                        ),
+                        Arc::new(
+                            Expr::from(x / &gcd)
+                        )
+                    );
+                }
+            }
+        }
+
+        Expr::Div(
+            Arc::new(
+                self
+            ),
+            Arc::new(
+                rhs
+            )
+        )
+    }
+}
+
+impl Div<i8> for Expr {
+    type Output = Self;
+
+    fn div(self, rhs: i8) -> Self::Output {
+        self / Expr::from(rhs)
+    }
+}
+
+impl Div<i16> for Expr {
+    type Output = Self;
+
+    fn div(self, rhs: i16) -> Self::Output {
+        self / Expr::from(rhs)
+    }
+}
+
+impl Div<i32> for Expr {
+    type Output = Self;
+
+    fn div(self, rhs: i32) -> Self::Output {
+        self / Expr::from(rhs)
+    }
+}
+
+impl Div<i64> for Expr {
+    type Output = Self;
+
+    fn div(self, rhs: i64) -> Self::Output {
+        self / Expr::from(rhs)
+    }
+}
+
+impl Div<i128> for Expr {
+    type Output = Self;
+
+    fn div(self, rhs: i128) -> Self::Output {
+        self / Expr::from(rhs)
+    }
+}
+
+impl Div<u8> for Expr {
+    type Output = Self;
+
+    fn div(self, rhs: u8) -> Self::Output {
+        self / Expr::from(rhs)
+    }
+}
+
+impl Div<u16> for Expr {
+    type Output = Self;
+
+    fn div(self, rhs: u16) -> Self::Output {
+        self / Expr::from(rhs)
+    }
+}
+
+impl Div<u32> for Expr {
+    type Output = Self;
+
+    fn div(self, rhs: u32) -> Self::Output {
+        self / Expr::from(rhs)
+    }
+}
+
+impl Div<u64> for Expr {
+    type Output = Self;
+
+    fn div(self, rhs: u64) -> Self::Output {
+        self / Expr::from(rhs)
+    }
+}
+
+impl Div<u128> for Expr {
+    type Output = Self;
+
+    fn div(self, rhs: u128) -> Self::Output {
+        self / Expr::from(rhs)
+    }
+}
+
+impl Div<isize> for Expr {
+    type Output = Self;
+
+    fn div(self, rhs: isize) -> Self::Output {
+        self / Expr::from(rhs)
+    }
+}
+
+impl Div<usize> for Expr {
+    type Output = Self;
+
+    fn div(self, rhs: usize) -> Self::Output {
+        self / Expr::from(rhs)
+    }
+}
+
+impl Div<f32> for Expr {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        self / Expr::from(rhs)
+    }
+}
+
+impl Div<f64> for Expr {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        self / Expr::from(rhs)
+    }
+}
+
+impl Div<Expr> for i8 {
+    type Output = Expr;
+
+    fn div(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) / rhs
+    }
+}
+
+impl Div<Expr> for i16 {
+    type Output = Expr;
+
+    fn div(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) / rhs
+    }
+}
+
+impl Div<Expr> for i32 {
+    type Output = Expr;
+
+    fn div(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) / rhs
+    }
+}
+
+impl Div<Expr> for i64 {
+    type Output = Expr;
+
+    fn div(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) / rhs
+    }
+}
+
+impl Div<Expr> for i128 {
+    type Output = Expr;
+
+    fn div(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) / rhs
+    }
+}
+
+impl Div<Expr> for u8 {
+    type Output = Expr;
+
+    fn div(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) / rhs
+    }
+}
+
+impl Div<Expr> for u16 {
+    type Output = Expr;
+
+    fn div(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) / rhs
+    }
+}
+
+impl Div<Expr> for u32 {
+    type Output = Expr;
+
+    fn div(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) / rhs
+    }
+}
+
+impl Div<Expr> for u64 {
+    type Output = Expr;
+
+    fn div(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) / rhs
+    }
+}
+
+impl Div<Expr> for u128 {
+    type Output = Expr;
+
+    fn div(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) / rhs
+    }
+}
+
+impl Div<Expr> for f32 {
+    type Output = Expr;
+
+    fn div(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) / rhs
+    }
+}
+
+impl Div<Expr> for f64 {
+    type Output = Expr;
+
+    fn div(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) / rhs
+    }
+}
+
+impl<'a> Div<&'a Expr> for &'a Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: &'a Expr) -> Self::Output {
+        self.clone() / rhs.clone()
+    }
+}
+
+impl Div<Expr> for &Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: Expr) -> Self::Output {
+        self.clone() / rhs
+    }
+}
+
+impl Div<&Expr> for Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: &Expr) -> Self::Output {
+        self / rhs.clone()
+    }
+}
+
+impl Div<i8> for &Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: i8) -> Self::Output {
+        self.clone() / Expr::from(rhs)
+    }
+}
+
+impl Div<i16> for &Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: i16) -> Self::Output {
+        self.clone() / Expr::from(rhs)
+    }
+}
+
+impl Div<i32> for &Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: i32) -> Self::Output {
+        self.clone() / Expr::from(rhs)
+    }
+}
+
+impl Div<i64> for &Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: i64) -> Self::Output {
+        self.clone() / Expr::from(rhs)
+    }
+}
+
+impl Div<i128> for &Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: i128) -> Self::Output {
+        self.clone() / Expr::from(rhs)
+    }
+}
+
+impl Div<u8> for &Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: u8) -> Self::Output {
+        self.clone() / Expr::from(rhs)
+    }
+}
+
+impl Div<u16> for &Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: u16) -> Self::Output {
+        self.clone() / Expr::from(rhs)
+    }
+}
+
+impl Div<u32> for &Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: u32) -> Self::Output {
+        self.clone() / Expr::from(rhs)
+    }
+}
+
+impl Div<u64> for &Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: u64) -> Self::Output {
+        self.clone() / Expr::from(rhs)
+    }
+}
+
+impl Div<u128> for &Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: u128) -> Self::Output {
+        self.clone() / Expr::from(rhs)
+    }
+}
+
+impl Div<isize> for &Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: isize) -> Self::Output {
+        self.clone() / Expr::from(rhs)
+    }
+}
+
+impl Div<usize> for &Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: usize) -> Self::Output {
+        self.clone() / Expr::from(rhs)
+    }
+}
+
+impl Div<f32> for &Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        self.clone() / Expr::from(rhs)
+    }
+}
+
+impl Div<f64> for &Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        self.clone() / Expr::from(rhs)
+    }
+}
+
+impl Div<&Expr> for i8 {
+    type Output = Expr;
+
+    fn div(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) / rhs.clone()
+    }
+}
+
+impl Div<&Expr> for i16 {
+    type Output = Expr;
+
+    fn div(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) / rhs.clone()
+    }
+}
+
+impl Div<&Expr> for i32 {
+    type Output = Expr;
+
+    fn div(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) / rhs.clone()
+    }
+}
+
+impl Div<&Expr> for i64 {
+    type Output = Expr;
+
+    fn div(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) / rhs.clone()
+    }
+}
+
+impl Div<&Expr> for i128 {
+    type Output = Expr;
+
+    fn div(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) / rhs.clone()
+    }
+}
+
+impl Div<&Expr> for u8 {
+    type Output = Expr;
+
+    fn div(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) / rhs.clone()
+    }
+}
+
+impl Div<&Expr> for u16 {
+    type Output = Expr;
+
+    fn div(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) / rhs.clone()
+    }
+}
+
+impl Div<&Expr> for u32 {
+    type Output = Expr;
+
+    fn div(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) / rhs.clone()
+    }
+}
+
+impl Div<&Expr> for u64 {
+    type Output = Expr;
+
+    fn div(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) / rhs.clone()
+    }
+}
+
+impl Div<&Expr> for u128 {
+    type Output = Expr;
+
+    fn div(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) / rhs.clone()
+    }
+}
+
+impl Div<&Expr> for f32 {
+    type Output = Expr;
+
+    fn div(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) / rhs.clone()
+    }
+}
 
-        if let Expr::Val(x) = rhs {
-            if x == 1 {
-                return self;
-            } else if x == 0 {
-                return Expr::from(0)
-            }
-        }
+impl Div<&Expr> for f64 {
+    type Output = Expr;
 
-        Expr::Mul(
-            Box::new(
+    fn div(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) / rhs.clone()
+    }
+}
+
+impl Rem for Expr {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        Expr::Rem(
+            Arc::new(
                 self
             ),
-            Box::new(
-                rhs
+            Arc::new(
+                Expr::from(rhs)
             )
         )
     }
 }
 
-impl Mul<i8> for Expr {
+impl Rem<i8> for Expr {
     type Output = Self;
 
-    fn mul(self, rhs: i8) -> Self::Output {
-        self * Expr::from(rhs)
+    fn rem(self, rhs: i8) -> Self::Output {
+        self % Expr::from(rhs)
     }
 }
 
-impl Mul<i16> for Expr {
+impl Rem<i16> for Expr {
     type Output = Self;
 
-    fn mul(self, rhs: i16) -> Self::Output {
-        self * Expr::from(rhs)
+    fn rem(self, rhs: i16) -> Self::Output {
+        self % Expr::from(rhs)
     }
 }
 
-impl Mul<i32> for Expr {
+impl Rem<i32> for Expr {
     type Output = Self;
 
-    fn mul(self, rhs: i32) -> Self::Output {
-        self * Expr::from(rhs)
+    fn rem(self, rhs: i32) -> Self::Output {
+        self % Expr::from(rhs)
     }
 }
 
-impl Mul<i64> for Expr {
+impl Rem<i64> for Expr {
     type Output = Self;
 
-    fn mul(self, rhs: i64) -> Self::Output {
-        self * Expr::from(rhs)
+    fn rem(self, rhs: i64) -> Self::Output {
+        self % Expr::from(rhs)
     }
 }
 
-impl Mul<i128> for Expr {
+impl Rem<i128> for Expr {
     type Output = Self;
 
-    fn mul(self, rhs: i128) -> Self::Output {
-        self * Expr::from(rhs)
+    fn rem(self, rhs: i128) -> Self::Output {
+        self % Expr::from(rhs)
     }
 }
 
-impl Mul<u8> for Expr {
+impl Rem<u8> for Expr {
     type Output = Self;
 
-    fn mul(self, rhs: u8) -> Self::Output {
-        self * Expr::from(rhs)
+    fn rem(self, rhs: u8) -> Self::Output {
+        self % Expr::from(rhs)
     }
 }
 
-impl Mul<u16> for Expr {
+impl Rem<u16> for Expr {
     type Output = Self;
 
-    fn mul(self, rhs: u16) -> Self::Output {
-        self * Expr::from(rhs)
+    fn rem(self, rhs: u16) -> Self::Output {
+        self % Expr::from(rhs)
     }
 }
 
-impl Mul<u32> for Expr {
+impl Rem<u32> for Expr {
     type Output = Self;
 
-    fn mul(self, rhs: u32) -> Self::Output {
-        self * Expr::from(rhs)
+    fn rem(self, rhs: u32) -> Self::Output {
+        self % Expr::from(rhs)
     }
 }
 
-impl Mul<u64> for Expr {
+impl Rem<u64> for Expr {
     type Output = Self;
 
-    fn mul(self, rhs: u64) -> Self::Output {
-        self * Expr::from(rhs)
+    fn rem(self, rhs: u64) -> Self::Output {
+        self % Expr::from(rhs)
     }
 }
 
-impl Mul<u128> for Expr {
+impl Rem<u128> for Expr {
     type Output = Self;
 
-    fn mul(self, rhs: u128) -> Self::Output {
-        self * Expr::from(rhs)
+    fn rem(self, rhs: u128) -> Self::Output {
+        self % Expr::from(rhs)
     }
 }
 
-impl Mul<isize> for Expr {
+impl Rem<isize> for Expr {
     type Output = Self;
 
-    fn mul(self, rhs: isize) -> Self::Output {
-        self * Expr::from(rhs)
+    fn rem(self, rhs: isize) -> Self::Output {
+        self % Expr::from(rhs)
     }
 }
 
-impl Mul<usize> for Expr {
+impl Rem<usize> for Expr {
     type Output = Self;
 
-    fn mul(self, rhs: usize) -> Self::Output {
-        self * Expr::from(rhs)
+    fn rem(self, rhs: usize) -> Self::Output {
+        self % Expr::from(rhs)
     }
 }
 
-impl Mul<f32> for Expr {
+impl Rem<f32> for Expr {
     type Output = Self;
 
-    fn mul(self, rhs: f32) -> Self::Output {
-        self * Expr::from(rhs)
+    fn rem(self, rhs: f32) -> Self::Output {
+        self % Expr::from(rhs)
     }
 }
 
-impl Mul<f64> for Expr {
+impl Rem<f64> for Expr {
     type Output = Self;
 
-    fn mul(self, rhs: f64) -> Self::Output {
-        self * Expr::from(rhs)
+    fn rem(self, rhs: f64) -> Self::Output {
+        self % Expr::from(rhs)
     }
 }
 
-impl Mul<Expr> for i8 {
+impl Rem<Expr> for i8 {
     type Output = Expr;
 
-    fn mul(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) * rhs
+    fn rem(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) % rhs
     }
 }
 
-impl Mul<Expr> for i16 {
+impl Rem<Expr> for i16 {
     type Output = Expr;
 
-    fn mul(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) * rhs
+    fn rem(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) % rhs
     }
 }
 
-impl Mul<Expr> for i32 {
+impl Rem<Expr> for i32 {
     type Output = Expr;
 
-    fn mul(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) * rhs
+    fn rem(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) % rhs
     }
 }
 
-impl Mul<Expr> for i64 {
+impl Rem<Expr> for i64 {
     type Output = Expr;
 
-    fn mul(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) * rhs
+    fn rem(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) % rhs
     }
 }
 
-impl Mul<Expr> for i128 {
+impl Rem<Expr> for i128 {
     type Output = Expr;
 
-    fn mul(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) * rhs
+    fn rem(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) % rhs
     }
 }
 
-impl Mul<Expr> for u8 {
+impl Rem<Expr> for u8 {
     type Output = Expr;
 
-    fn mul(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) * rhs
+    fn rem(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) % rhs
     }
 }
 
-impl Mul<Expr> for u16 {
+impl Rem<Expr> for u16 {
     type Output = Expr;
 
-    fn mul(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) * rhs
+    fn rem(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) % rhs
     }
 }
 
-impl Mul<Expr> for u32 {
+impl Rem<Expr> for u32 {
     type Output = Expr;
 
-    fn mul(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) * rhs
+    fn rem(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) % rhs
     }
 }
 
-impl Mul<Expr> for u64 {
+impl Rem<Expr> for u64 {
     type Output = Expr;
 
-    fn mul(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) * rhs
+    fn rem(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) % rhs
     }
 }
 
-impl Mul<Expr> for u128 {
+impl Rem<Expr> for u128 {
     type Output = Expr;
 
-    fn mul(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) * rhs
+    fn rem(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) % rhs
     }
 }
 
-impl Mul<Expr> for f32 {
+impl Rem<Expr> for f32 {
     type Output = Expr;
 
-    fn mul(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) * rhs
+    fn rem(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) % rhs
     }
 }
 
-impl Mul<Expr> for f64 {
+impl Rem<Expr> for f64 {
     type Output = Expr;
 
-    fn mul(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) * rhs
+    fn rem(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) % rhs
     }
 }
 
-impl Div for Expr {
-    type Output = Self;
+impl<'a> Rem<&'a Expr> for &'a Expr {
+    type Output = Expr;
 
-    fn div(self, rhs: Self) -> Self::Output {
-        let rhs = Expr::from(rhs);
+    fn rem(self, rhs: &'a Expr) -> Self::Output {
+        self.clone() % rhs.clone()
+    }
+}
 
-        if let Expr::Val(x) = rhs {
-            if x == 1 {
-                return self;
-            } else {
-                if let Expr::Val(y) = self {
-                    let gcd = gcd(x, y);
+impl Rem<Expr> for &Expr {
+    type Output = Expr;
 
-                    return Expr::Div(
-                        Box::new(
-                            Expr::from(y / gcd)
-                        ),
-                        Box::new(
-                            Expr::from(x / gcd)
-                        )
-                    );
-                }
-            }
-        }
+    fn rem(self, rhs: Expr) -> Self::Output {
+        self.clone() % rhs
+    }
+}
 
-        Expr::Div(
-            Box::new(
-                self
-            ),
-            Box::new(
-                rhs
-            )
-        )
+impl Rem<&Expr> for Expr {
+    type Output = Expr;
+
+    fn rem(self, rhs: &Expr) -> Self::Output {
+        self % rhs.clone()
     }
 }
 
-impl Div<i8> for Expr {
-    type Output = Self;
+impl Rem<i8> for &Expr {
+    type Output = Expr;
 
-    fn div(self, rhs: i8) -> Self::Output {
-        self / Expr::from(rhs)
+    fn rem(self, rhs: i8) -> Self::Output {
+        self.clone() % Expr::from(rhs)
     }
 }
 
-impl Div<i16> for Expr {
-    type Output = Self;
+impl Rem<i16> for &Expr {
+    type Output = Expr;
 
-    fn div(self, rhs: i16) -> Self::Output {
-        self / Expr::from(rhs)
+    fn rem(self, rhs: i16) -> Self::Output {
+        self.clone() % Expr::from(rhs)
     }
 }
 
-impl Div<i32> for Expr {
-    type Output = Self;
+impl Rem<i32> for &Expr {
+    type Output = Expr;
 
-    fn div(self, rhs: i32) -> Self::Output {
-        self / Expr::from(rhs)
+    fn rem(self, rhs: i32) -> Self::Output {
+        self.clone() % Expr::from(rhs)
     }
 }
 
-impl Div<i64> for Expr {
-    type Output = Self;
+impl Rem<i64> for &Expr {
+    type Output = Expr;
 
-    fn div(self, rhs: i64) -> Self::Output {
-        self / Expr::from(rhs)
+    fn rem(self, rhs: i64) -> Self::Output {
+        self.clone() % Expr::from(rhs)
     }
 }
 
-impl Div<i128> for Expr {
-    type Output = Self;
+impl Rem<i128> for &Expr {
+    type Output = Expr;
 
-    fn div(self, rhs: i128) -> Self::Output {
-        self / Expr::from(rhs)
+    fn rem(self, rhs: i128) -> Self::Output {
+        self.clone() % Expr::from(rhs)
     }
 }
 
-impl Div<u8> for Expr {
-    type Output = Self;
+impl Rem<u8> for &Expr {
+    type Output = Expr;
 
-    fn div(self, rhs: u8) -> Self::Output {
-        self / Expr::from(rhs)
+    fn rem(self, rhs: u8) -> Self::Output {
+        self.clone() % Expr::from(rhs)
     }
 }
 
-impl Div<u16> for Expr {
-    type Output = Self;
+impl Rem<u16> for &Expr {
+    type Output = Expr;
 
-    fn div(self, rhs: u16) -> Self::Output {
-        self / Expr::from(rhs)
+    fn rem(self, rhs: u16) -> Self::Output {
+        self.clone() % Expr::from(rhs)
     }
 }
 
-impl Div<u32> for Expr {
-    type Output = Self;
+impl Rem<u32> for &Expr {
+    type Output = Expr;
 
-    fn div(self, rhs: u32) -> Self::Output {
-        self / Expr::from(rhs)
+    fn rem(self, rhs: u32) -> Self::Output {
+        self.clone() % Expr::from(rhs)
     }
 }
 
-impl Div<u64> for Expr {
-    type Output = Self;
+impl Rem<u64> for &Expr {
+    type Output = Expr;
 
-    fn div(self, rhs: u64) -> Self::Output {
-        self / Expr::from(rhs)
+    fn rem(self, rhs: u64) -> Self::Output {
+        self.clone() % Expr::from(rhs)
     }
 }
 
-impl Div<u128> for Expr {
-    type Output = Self;
+impl Rem<u128> for &Expr {
+    type Output = Expr;
 
-    fn div(self, rhs: u128) -> Self::Output {
-        self / Expr::from(rhs)
+    fn rem(self, rhs: u128) -> Self::Output {
+        self.clone() % Expr::from(rhs)
     }
 }
 
-impl Div<isize> for Expr {
-    type Output = Self;
+impl Rem<isize> for &Expr {
+    type Output = Expr;
 
-    fn div(self, rhs: isize) -> Self::Output {
-        self / Expr::from(rhs)
+    fn rem(self, rhs: isize) -> Self::Output {
+        self.clone() % Expr::from(rhs)
     }
 }
 
-impl Div<usize> for Expr {
-    type Output = Self;
+impl Rem<usize> for &Expr {
+    type Output = Expr;
 
-    fn div(self, rhs: usize) -> Self::Output {
-        self / Expr::from(rhs)
+    fn rem(self, rhs: usize) -> Self::Output {
+        self.clone() % Expr::from(rhs)
     }
 }
 
-impl Div<f32> for Expr {
-    type Output = Self;
+impl Rem<f32> for &Expr {
+    type Output = Expr;
 
-    fn div(self, rhs: f32) -> Self::Output {
-        self / Expr::from(rhs)
+    fn rem(self, rhs: f32) -> Self::Output {
+        self.clone() % Expr::from(rhs)
     }
 }
 
-impl Div<f64> for Expr {
-    type Output = Self;
+impl Rem<f64> for &Expr {
+    type Output = Expr;
 
-    fn div(self, rhs: f64) -> Self::Output {
-        self / Expr::from(rhs)
+    fn rem(self, rhs: f64) -> Self::Output {
+        self.clone() % Expr::from(rhs)
     }
 }
 
-impl Div<Expr> for i8 {
+impl Rem<&Expr> for i8 {
     type Output = Expr;
 
-    fn div(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) / rhs
+    fn rem(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) % rhs.clone()
     }
 }
 
-impl Div<Expr> for i16 {
+impl Rem<&Expr> for i16 {
     type Output = Expr;
 
-    fn div(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) / rhs
+    fn rem(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) % rhs.clone()
     }
 }
 
-impl Div<Expr> for i32 {
+impl Rem<&Expr> for i32 {
     type Output = Expr;
 
-    fn div(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) / rhs
+    fn rem(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) % rhs.clone()
     }
 }
 
-impl Div<Expr> for i64 {
+impl Rem<&Expr> for i64 {
     type Output = Expr;
 
-    fn div(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) / rhs
+    fn rem(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) % rhs.clone()
     }
 }
 
-impl Div<Expr> for i128 {
+impl Rem<&Expr> for i128 {
     type Output = Expr;
 
-    fn div(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) / rhs
+    fn rem(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) % rhs.clone()
     }
 }
 
-impl Div<Expr> for u8 {
+impl Rem<&Expr> for u8 {
     type Output = Expr;
 
-    fn div(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) / rhs
+    fn rem(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) % rhs.clone()
     }
 }
 
-impl Div<Expr> for u16 {
+impl Rem<&Expr> for u16 {
     type Output = Expr;
 
-    fn div(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) / rhs
+    fn rem(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) % rhs.clone()
     }
 }
 
-impl Div<Expr> for u32 {
+impl Rem<&Expr> for u32 {
     type Output = Expr;
 
-    fn div(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) / rhs
+    fn rem(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) % rhs.clone()
     }
 }
 
-impl Div<Expr> for u64 {
+impl Rem<&Expr> for u64 {
     type Output = Expr;
 
-    fn div(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) / rhs
+    fn rem(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) % rhs.clone()
     }
 }
 
-impl Div<Expr> for u128 {
+impl Rem<&Expr> for u128 {
     type Output = Expr;
 
-    fn div(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) / rhs
+    fn rem(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) % rhs.clone()
     }
 }
 
-impl Div<Expr> for f32 {
+impl Rem<&Expr> for f32 {
     type Output = Expr;
 
-    fn div(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) / rhs
+    fn rem(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) % rhs.clone()
     }
 }
 
-impl Div<Expr> for f64 {
+impl Rem<&Expr> for f64 {
     type Output = Expr;
 
-    fn div(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) / rhs
+    fn rem(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) % rhs.clone()
     }
 }
 
-impl Rem for Expr {
+impl BitXor for Expr {
     type Output = Self;
 
-    fn rem(self, rhs: Self) -> Self::Output {
-        Expr::Rem(
-            Box::new(
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        let rhs = Expr::from(rhs);
+
+        if let Expr::Val(x) = &rhs {
+            if x.is_one() {
+                return self;
+            } else if x.is_zero() {
+                return Expr::from(1);
+            }
+        }
+
+        if let Expr::Val(x) = &self {
+            if x.is_zero() {
+                return Expr::from(0);
+            }
+        }
+
+        Expr::Exp(
+            Arc::new(
                 self
             ),
-            Box::new(
-                Expr::from(rhs)
+            Arc::new(
+                rhs
             )
         )
     }
 }
 
-impl Rem<i8> for Expr {
+impl BitXor<i8> for Expr {
     type Output = Self;
 
-    fn rem(self, rhs: i8) -> Self::Output {
-        self % Expr::from(rhs)
+    fn bitxor(self, rhs: i8) -> Self::Output {
+        self ^ Expr::from(rhs)
     }
 }
 
-impl Rem<i16> for Expr {
+impl BitXor<i16> for Expr {
     type Output = Self;
 
-    fn rem(self, rhs: i16) -> Self::Output {
-        self % Expr::from(rhs)
+    fn bitxor(self, rhs: i16) -> Self::Output {
+        self ^ Expr::from(rhs)
     }
 }
 
-impl Rem<i32> for Expr {
+impl BitXor<i32> for Expr {
     type Output = Self;
 
-    fn rem(self, rhs: i32) -> Self::Output {
-        self % Expr::from(rhs)
+    fn bitxor(self, rhs: i32) -> Self::Output {
+        self ^ Expr::from(rhs)
     }
 }
 
-impl Rem<i64> for Expr {
+impl BitXor<i64> for Expr {
     type Output = Self;
 
-    fn rem(self, rhs: i64) -> Self::Output {
-        self % Expr::from(rhs)
+    fn bitxor(self, rhs: i64) -> Self::Output {
+        self ^ Expr::from(rhs)
     }
 }
 
-impl Rem<i128> for Expr {
+impl BitXor<i128> for Expr {
     type Output = Self;
 
-    fn rem(self, rhs: i128) -> Self::Output {
-        self % Expr::from(rhs)
+    fn bitxor(self, rhs: i128) -> Self::Output {
+        self ^ Expr::from(rhs)
     }
 }
 
-impl Rem<u8> for Expr {
+impl BitXor<u8> for Expr {
     type Output = Self;
 
-    fn rem(self, rhs: u8) -> Self::Output {
-        self % Expr::from(rhs)
+    fn bitxor(self, rhs: u8) -> Self::Output {
+        self ^ Expr::from(rhs)
     }
 }
 
-impl Rem<u16> for Expr {
+impl BitXor<u16> for Expr {
     type Output = Self;
 
-    fn rem(self, rhs: u16) -> Self::Output {
-        self % Expr::from(rhs)
+    fn bitxor(self, rhs: u16) -> Self::Output {
+        self ^ Expr::from(rhs)
     }
 }
 
-impl Rem<u32> for Expr {
+impl BitXor<u32> for Expr {
     type Output = Self;
 
-    fn rem(self, rhs: u32) -> Self::Output {
-        self % Expr::from(rhs)
+    fn bitxor(self, rhs: u32) -> Self::Output {
+        self ^ Expr::from(rhs)
     }
 }
 
-impl Rem<u64> for Expr {
+impl BitXor<u64> for Expr {
     type Output = Self;
 
-    fn rem(self, rhs: u64) -> Self::Output {
-        self % Expr::from(rhs)
+    fn bitxor(self, rhs: u64) -> Self::Output {
+        self ^ Expr::from(rhs)
     }
 }
 
-impl Rem<u128> for Expr {
+impl BitXor<u128> for Expr {
     type Output = Self;
 
-    fn rem(self, rhs: u128) -> Self::Output {
-        self % Expr::from(rhs)
+    fn bitxor(self, rhs: u128) -> Self::Output {
+        self ^ Expr::from(rhs)
     }
 }
 
-impl Rem<isize> for Expr {
+impl BitXor<isize> for Expr {
     type Output = Self;
 
-    fn rem(self, rhs: isize) -> Self::Output {
-        self % Expr::from(rhs)
+    fn bitxor(self, rhs: isize) -> Self::Output {
+        self ^ Expr::from(rhs)
     }
 }
 
-impl Rem<usize> for Expr {
+impl BitXor<usize> for Expr {
     type Output = Self;
 
-    fn rem(self, rhs: usize) -> Self::Output {
-        self % Expr::from(rhs)
+    fn bitxor(self, rhs: usize) -> Self::Output {
+        self ^ Expr::from(rhs)
     }
 }
 
-impl Rem<f32> for Expr {
+impl BitXor<f32> for Expr {
     type Output = Self;
 
-    fn rem(self, rhs: f32) -> Self::Output {
-        self % Expr::from(rhs)
+    fn bitxor(self, rhs: f32) -> Self::Output {
+        self ^ Expr::from(rhs)
     }
 }
 
-impl Rem<f64> for Expr {
+impl BitXor<f64> for Expr {
     type Output = Self;
 
-    fn rem(self, rhs: f64) -> Self::Output {
-        self % Expr::from(rhs)
+    fn bitxor(self, rhs: f64) -> Self::Output {
+        self ^ Expr::from(rhs)
     }
 }
 
-impl Rem<Expr> for i8 {
+impl BitXor<Expr> for i8 {
     type Output = Expr;
 
-    fn rem(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) % rhs
+    fn bitxor(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) ^ rhs
     }
 }
 
-impl Rem<Expr> for i16 {
+impl BitXor<Expr> for i16 {
     type Output = Expr;
 
-    fn rem(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) % rhs
+    fn bitxor(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) ^ rhs
     }
 }
 
-impl Rem<Expr> for i32 {
+impl BitXor<Expr> for i32 {
     type Output = Expr;
 
-    fn rem(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) % rhs
+    fn bitxor(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) ^ rhs
     }
 }
 
-impl Rem<Expr> for i64 {
+impl BitXor<Expr> for i64 {
     type Output = Expr;
 
-    fn rem(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) % rhs
+    fn bitxor(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) ^ rhs
     }
 }
 
-impl Rem<Expr> for i128 {
+impl BitXor<Expr> for i128 {
     type Output = Expr;
 
-    fn rem(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) % rhs
+    fn bitxor(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) ^ rhs
     }
 }
 
-impl Rem<Expr> for u8 {
+impl BitXor<Expr> for u8 {
     type Output = Expr;
 
-    fn rem(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) % rhs
+    fn bitxor(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) ^ rhs
     }
 }
 
-impl Rem<Expr> for u16 {
+impl BitXor<Expr> for u16 {
     type Output = Expr;
 
-    fn rem(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) % rhs
+    fn bitxor(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) ^ rhs
     }
 }
 
-impl Rem<Expr> for u32 {
+impl BitXor<Expr> for u32 {
     type Output = Expr;
 
-    fn rem(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) % rhs
+    fn bitxor(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) ^ rhs
     }
 }
 
-impl Rem<Expr> for u64 {
+impl BitXor<Expr> for u64 {
     type Output = Expr;
 
-    fn rem(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) % rhs
+    fn bitxor(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) ^ rhs
     }
 }
 
-impl Rem<Expr> for u128 {
+impl BitXor<Expr> for u128 {
     type Output = Expr;
 
-    fn rem(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) % rhs
+    fn bitxor(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) ^ rhs
     }
 }
 
-impl Rem<Expr> for f32 {
+impl BitXor<Expr> for f32 {
     type Output = Expr;
 
-    fn rem(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) % rhs
+    fn bitxor(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) ^ rhs
     }
 }
 
-impl Rem<Expr> for f64 {
+impl BitXor<Expr> for f64 {
     type Output = Expr;
 
-    fn rem(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) % rhs
+    fn bitxor(self, rhs: Expr) -> Self::Output {
+        Expr::from(self) ^ rhs
     }
 }
 
-impl BitXor for Expr {
-    type Output = Self;
+impl<'a> BitXor<&'a Expr> for &'a Expr {
+    type Output = Expr;
 
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        let rhs = Expr::from(rhs);
+    fn bitxor(self, rhs: &'a Expr) -> Self::Output {
+        self.clone() ^ rhs.clone()
+    }
+}
 
-        if let Expr::Val(x) = rhs {
-            if x == 1 {
-                return self;
-            } else if x == 0 {
-                return Expr::from(1);
-            }
-        }
+impl BitXor<Expr> for &Expr {
+    type Output = Expr;
 
-        if let Expr::Val(x) = self {
-            if x == 0 {
-                return Expr::from(x);
-            }
-        }
+    fn bitxor(self, rhs: Expr) -> Self::Output {
+        self.clone() ^ rhs
+    }
+}
+
+impl BitXor<&Expr> for Expr {
+    type Output = Expr;
 
-        Expr::Exp(
-            Box::new(
-                self
-            ),
-            Box::new(
-                rhs
-            )
-        )
+    fn bitxor(self, rhs: &Expr) -> Self::Output {
+        self ^ rhs.clone()
     }
 }
 
-impl BitXor<i8> for Expr {
-    type Output = Self;
+impl BitXor<i8> for &Expr {
+    type Output = Expr;
 
     fn bitxor(self, rhs: i8) -> Self::Output {
-        self ^ Expr::from(rhs)
+        self.clone() ^ Expr::from(rhs)
     }
 }
 
-impl BitXor<i16> for Expr {
-    type Output = Self;
+impl BitXor<i16> for &Expr {
+    type Output = Expr;
 
     fn bitxor(self, rhs: i16) -> Self::Output {
-        self ^ Expr::from(rhs)
+        self.clone() ^ Expr::from(rhs)
     }
 }
 
-impl BitXor<i32> for Expr {
-    type Output = Self;
+impl BitXor<i32> for &Expr {
+    type Output = Expr;
 
     fn bitxor(self, rhs: i32) -> Self::Output {
-        self ^ Expr::from(rhs)
+        self.clone() ^ Expr::from(rhs)
     }
 }
 
-impl BitXor<i64> for Expr {
-    type Output = Self;
+impl BitXor<i64> for &Expr {
+    type Output = Expr;
 
     fn bitxor(self, rhs: i64) -> Self::Output {
-        self ^ Expr::from(rhs)
+        self.clone() ^ Expr::from(rhs)
     }
 }
 
-impl BitXor<i128> for Expr {
-    type Output = Self;
+impl BitXor<i128> for &Expr {
+    type Output = Expr;
 
     fn bitxor(self, rhs: i128) -> Self::Output {
-        self ^ Expr::from(rhs)
+        self.clone() ^ Expr::from(rhs)
     }
 }
 
-impl BitXor<u8> for Expr {
-    type Output = Self;
+impl BitXor<u8> for &Expr {
+    type Output = Expr;
 
     fn bitxor(self, rhs: u8) -> Self::Output {
-        self ^ Expr::from(rhs)
+        self.clone() ^ Expr::from(rhs)
     }
 }
 
-impl BitXor<u16> for Expr {
-    type Output = Self;
+impl BitXor<u16> for &Expr {
+    type Output = Expr;
 
     fn bitxor(self, rhs: u16) -> Self::Output {
-        self ^ Expr::from(rhs)
+        self.clone() ^ Expr::from(rhs)
     }
 }
 
-impl BitXor<u32> for Expr {
-    type Output = Self;
+impl BitXor<u32> for &Expr {
+    type Output = Expr;
 
     fn bitxor(self, rhs: u32) -> Self::Output {
-        self ^ Expr::from(rhs)
+        self.clone() ^ Expr::from(rhs)
     }
 }
 
-impl BitXor<u64> for Expr {
-    type Output = Self;
+impl BitXor<u64> for &Expr {
+    type Output = Expr;
 
     fn bitxor(self, rhs: u64) -> Self::Output {
-        self ^ Expr::from(rhs)
+        self.clone() ^ Expr::from(rhs)
     }
 }
 
-impl BitXor<u128> for Expr {
-    type Output = Self;
+impl BitXor<u128> for &Expr {
+    type Output = Expr;
 
     fn bitxor(self, rhs: u128) -> Self::Output {
-        self ^ Expr::from(rhs)
+        self.clone() ^ Expr::from(rhs)
     }
 }
 
-impl BitXor<isize> for Expr {
-    type Output = Self;
+impl BitXor<isize> for &Expr {
+    type Output = Expr;
 
     fn bitxor(self, rhs: isize) -> Self::Output {
-        self ^ Expr::from(rhs)
+        self.clone() ^ Expr::from(rhs)
     }
 }
 
-impl BitXor<usize> for Expr {
-    type Output = Self;
+impl BitXor<usize> for &Expr {
+    type Output = Expr;
 
     fn bitxor(self, rhs: usize) -> Self::Output {
-        self ^ Expr::from(rhs)
+        self.clone() ^ Expr::from(rhs)
     }
 }
 
-impl BitXor<f32> for Expr {
-    type Output = Self;
+impl BitXor<f32> for &Expr {
+    type Output = Expr;
 
     fn bitxor(self, rhs: f32) -> Self::Output {
-        self ^ Expr::from(rhs)
+        self.clone() ^ Expr::from(rhs)
     }
 }
 
-impl BitXor<f64> for Expr {
-    type Output = Self;
+impl BitXor<f64> for &Expr {
+    type Output = Expr;
 
     fn bitxor(self, rhs: f64) -> Self::Output {
-        self ^ Expr::from(rhs)
+        self.clone() ^ Expr::from(rhs)
     }
 }
 
-impl BitXor<Expr> for i8 {
+impl BitXor<&Expr> for i8 {
     type Output = Expr;
 
-    fn bitxor(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) ^ rhs
+    fn bitxor(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) ^ rhs.clone()
     }
 }
 
-impl BitXor<Expr> for i16 {
+impl BitXor<&Expr> for i16 {
     type Output = Expr;
 
-    fn bitxor(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) ^ rhs
+    fn bitxor(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) ^ rhs.clone()
     }
 }
 
-impl BitXor<Expr> for i32 {
+impl BitXor<&Expr> for i32 {
     type Output = Expr;
 
-    fn bitxor(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) ^ rhs
+    fn bitxor(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) ^ rhs.clone()
     }
 }
 
-impl BitXor<Expr> for i64 {
+impl BitXor<&Expr> for i64 {
     type Output = Expr;
 
-    fn bitxor(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) ^ rhs
+    fn bitxor(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) ^ rhs.clone()
     }
 }
 
-impl BitXor<Expr> for i128 {
+impl BitXor<&Expr> for i128 {
     type Output = Expr;
 
-    fn bitxor(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) ^ rhs
+    fn bitxor(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) ^ rhs.clone()
     }
 }
 
-impl BitXor<Expr> for u8 {
+impl BitXor<&Expr> for u8 {
     type Output = Expr;
 
-    fn bitxor(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) ^ rhs
+    fn bitxor(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) ^ rhs.clone()
     }
 }
 
-impl BitXor<Expr> for u16 {
+impl BitXor<&Expr> for u16 {
     type Output = Expr;
 
-    fn bitxor(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) ^ rhs
+    fn bitxor(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) ^ rhs.clone()
     }
 }
 
-impl BitXor<Expr> for u32 {
+impl BitXor<&Expr> for u32 {
     type Output = Expr;
 
-    fn bitxor(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) ^ rhs
+    fn bitxor(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) ^ rhs.clone()
     }
 }
 
-impl BitXor<Expr> for u64 {
+impl BitXor<&Expr> for u64 {
     type Output = Expr;
 
-    fn bitxor(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) ^ rhs
+    fn bitxor(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) ^ rhs.clone()
     }
 }
 
-impl BitXor<Expr> for u128 {
+impl BitXor<&Expr> for u128 {
     type Output = Expr;
 
-    fn bitxor(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) ^ rhs
+    fn bitxor(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) ^ rhs.clone()
     }
 }
 
-impl BitXor<Expr> for f32 {
+impl BitXor<&Expr> for f32 {
     type Output = Expr;
 
-    fn bitxor(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) ^ rhs
+    fn bitxor(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) ^ rhs.clone()
     }
 }
 
-impl BitXor<Expr> for f64 {
+impl BitXor<&Expr> for f64 {
     type Output = Expr;
 
-    fn bitxor(self, rhs: Expr) -> Self::Output {
-        Expr::from(self) ^ rhs
+    fn bitxor(self, rhs: &Expr) -> Self::Output {
+        Expr::from(self) ^ rhs.clone()
     }
 }
 
 impl PartialEq for Expr {
     fn eq(&self, rhs: &Self) -> bool {
-        self.eval() == rhs.eval()
+        self.partial_cmp(rhs) == Some(Ordering::Equal)
     }
 }
 
 impl PartialEq<i8> for Expr {
     fn eq(&self, rhs: &i8) -> bool {
-        self.eval() == Expr::from(rhs).eval()
+        self.partial_cmp(&Expr::from(*rhs)) == Some(Ordering::Equal)
     }
 }
 
 impl PartialEq<i16> for Expr {
     fn eq(&self, rhs: &i16) -> bool {
-        self.eval() == Expr::from(rhs).eval()
+        self.partial_cmp(&Expr::from(*rhs)) == Some(Ordering::Equal)
     }
 }
 
 impl PartialEq<i32> for Expr {
     fn eq(&self, rhs: &i32) -> bool {
-        self.eval() == Expr::from(rhs).eval()
+        self.partial_cmp(&Expr::from(*rhs)) == Some(Ordering::Equal)
     }
 }
 
 impl PartialEq<i64> for Expr {
     fn eq(&self, rhs: &i64) -> bool {
-        self.eval() == Expr::from(rhs).eval()
+        self.partial_cmp(&Expr::from(*rhs)) == Some(Ordering::Equal)
     }
 }
 
 impl PartialEq<i128> for Expr {
     fn eq(&self, rhs: &i128) -> bool {
-        self.eval() == Expr::from(rhs).eval()
+        self.partial_cmp(&Expr::from(*rhs)) == Some(Ordering::Equal)
     }
 }
 
 impl PartialEq<u8> for Expr {
     fn eq(&self, rhs: &u8) -> bool {
-        self.eval() == Expr::from(rhs).eval()
+        self.partial_cmp(&Expr::from(*rhs)) == Some(Ordering::Equal)
     }
 }
 
 impl PartialEq<u16> for Expr {
     fn eq(&self, rhs: &u16) -> bool {
-        self.eval() == Expr::from(rhs).eval()
+        self.partial_cmp(&Expr::from(*rhs)) == Some(Ordering::Equal)
     }
 }
 
 impl PartialEq<u32> for Expr {
     fn eq(&self, rhs: &u32) -> bool {
-        self.eval() == Expr::from(rhs).eval()
+        self.partial_cmp(&Expr::from(*rhs)) == Some(Ordering::Equal)
     }
 }
 
 impl PartialEq<u64> for Expr {
     fn eq(&self, rhs: &u64) -> bool {
-        self.eval() == Expr::from(rhs).eval()
+        self.partial_cmp(&Expr::from(*rhs)) == Some(Ordering::Equal)
     }
 }
 
 impl PartialEq<u128> for Expr {
     fn eq(&self, rhs: &u128) -> bool {
-        self.eval() == Expr::from(rhs).eval()
+        self.partial_cmp(&Expr::from(*rhs)) == Some(Ordering::Equal)
     }
 }
 
 impl PartialEq<isize> for Expr {
     fn eq(&self, rhs: &isize) -> bool {
-        self.eval() == Expr::from(rhs).eval()
+        self.partial_cmp(&Expr::from(*rhs)) == Some(Ordering::Equal)
     }
 }
 
 impl PartialEq<usize> for Expr {
     fn eq(&self, rhs: &usize) -> bool {
-        self.eval() == Expr::from(rhs).eval()
+        self.partial_cmp(&Expr::from(*rhs)) == Some(Ordering::Equal)
     }
 }
 
 impl PartialEq<f32> for Expr {
     fn eq(&self, rhs: &f32) -> bool {
-        self.eval() == Expr::from(rhs).eval()
+        self.partial_cmp(&Expr::from(*rhs)) == Some(Ordering::Equal)
     }
 }
 
 impl PartialEq<f64> for Expr {
     fn eq(&self, rhs: &f64) -> bool {
-        self.eval() == Expr::from(rhs).eval()
+        self.partial_cmp(&Expr::from(*rhs)) == Some(Ordering::Equal)
     }
 }
 
-impl Eq for Expr {}
-
 impl PartialOrd for Expr {
     fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
-        let lhs = self.eval();
-        let rhs = rhs.eval();
-
-        if lhs < rhs {
-            Some(Ordering::Less)
-        } else if lhs > rhs {
-            Some(Ordering::Greater)
-        } else {
-            Some(Ordering::Equal)
-        }
-    }
-}
-
-impl Ord for Expr {
-    fn cmp(&self, rhs: &Self) -> Ordering {
-        let lhs = self.eval();
-        let rhs = rhs.eval();
-
-        if lhs < rhs {
-            Ordering::Less
-        } else if lhs > rhs {
-            Ordering::Greater
-        } else {
-            Ordering::Equal
+        match self.cmp_exact(rhs) {
+            Ok(ordering) => Some(ordering),
+            Err(_) => self.eval().partial_cmp(&rhs.eval()),
         }
     }
 }
 
 impl fmt::Display for Expr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Expr::Val(x) => write!(f, "{}", x),
-            Expr::Add(x, y) => write!(f, "({} + {})", x, y),
-            Expr::Sub(x, y) => write!(f, "({} - {})", x, y),
-            Expr::Mul(x, y) => write!(f, "({} * {})", x, y),
-            Expr::Div(x, y) => write!(f, "({} / {})", x, y),
-            Expr::Rem(x, y) => write!(f, "({} % {})", x, y),
-            Expr::Exp(x, y) => write!(f, "({} ^ {})", x, y),
-        }
+        write!(f, "{}", self.to_string_with(&PrintOptions::default()))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Expr;
+    use std::cmp::Ordering;
+    use std::sync::Arc;
+    use super::{ Expr, EvalError, EvalErrorKind };
+    use super::environment::Environment;
+    use super::function::Function;
+    use crate::precise::integer::Integer;
+    use crate::precise::rational::Rational;
 
     #[test]
     fn from_i16() {
         let result = Expr::from(3_i16);
-        let expected_result = Expr::Val(3);
+        let expected_result = Expr::Val(Integer::from(3));
 
         assert_eq!(result, expected_result);
     }
@@ -1785,7 +3481,7 @@ mod tests {
     #[test]
     fn from_i32() {
         let result = Expr::from(3_i32);
-        let expected_result = Expr::Val(3);
+        let expected_result = Expr::Val(Integer::from(3));
 
         assert_eq!(result, expected_result);
     }
@@ -1794,28 +3490,54 @@ mod tests {
     fn from_f32() {
         let result = Expr::from(3.2_f32);
         let expected_result = Expr::Div(
-            Box::new(
-                Expr::from(16)
-            ), Box::new(
-                Expr::from(5)
+            Arc::new(
+                Expr::from(13421773)
+            ), Arc::new(
+                Expr::from(4194304)
             )
         );
 
-        assert_eq!(result, expected_result);
+        assert!(result.structural_eq(&expected_result));
     }
 
     #[test]
     fn from_f64() {
         let result = Expr::from(3.2_f64);
         let expected_result = Expr::Div(
-            Box::new(
+            Arc::new(
+                Expr::from(3602879701896397_i64)
+            ), Arc::new(
+                Expr::from(1125899906842624_i64)
+            )
+        );
+
+        assert!(result.structural_eq(&expected_result));
+    }
+
+    #[test]
+    fn from_f64_extremes() {
+        assert_eq!(Expr::from(1e-12).eval_exact().unwrap().to_f64(), 1e-12);
+        assert_eq!(Expr::from(f64::MAX).eval_exact().unwrap().to_f64(), f64::MAX);
+        assert_eq!(Expr::from(-5e-324).eval_exact().unwrap().to_f64(), -5e-324);
+        assert!(Expr::from(f64::NAN).eval().is_nan());
+        assert_eq!(Expr::from(f64::NEG_INFINITY).eval(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn approximate_f64() {
+        let result = Expr::approximate_f64(3.2, 1000);
+        let expected_result = Expr::Div(
+            Arc::new(
                 Expr::from(16)
-            ), Box::new(
+            ), Arc::new(
                 Expr::from(5)
             )
         );
 
-        assert_eq!(result, expected_result);
+        assert!(result.structural_eq(&expected_result));
+        assert_eq!(Expr::approximate_f64(0.333333, 1000).to_string(), "1 / 3");
+        assert_eq!(Expr::approximate_f64(-std::f64::consts::PI, 1000).to_string(), "-355 / 113");
+        assert_eq!(Expr::approximate_f64(2.0, 1).to_string(), "2");
     }
 
     #[test]
@@ -1905,4 +3627,283 @@ mod tests {
 
         assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn reference_operators() {
+        let x = Expr::var("x");
+        let y = Expr::var("y");
+
+        assert_eq!((&x + &y).to_string(), "x + y");
+        assert_eq!((&x - y.clone()).to_string(), "x - y");
+        assert_eq!((x.clone() * &y).to_string(), "x * y");
+
+        let quotient: Expr = &x / 2;
+        let remainder: Expr = 3 % &y;
+        let power: Expr = &x ^ 2;
+
+        assert_eq!(quotient.to_string(), "x / 2");
+        assert_eq!(remainder.to_string(), "3 % y");
+        assert_eq!(power.to_string(), "x ^ 2");
+        assert!((&Expr::from(1) * &x).structural_eq(&x));
+    }
+
+    #[test]
+    fn shared_children() {
+        let lhs = Expr::from(1) + Expr::var("x");
+        let expression = &lhs * &lhs;
+        let copy = expression.clone();
+
+        match (&expression, &copy) {
+            (Expr::Mul(a, b), Expr::Mul(c, d)) => {
+                assert!(Arc::ptr_eq(a, c));
+                assert!(Arc::ptr_eq(b, d));
+            },
+            _ => panic!("expected a product"),
+        }
+    }
+
+    #[test]
+    fn eval_exact() {
+        let test_expression: Expr = Expr::from(1) / 3 * 3;
+
+        let result = test_expression.eval_exact();
+        let expected_result = Ok(Rational::from(1));
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn eval_exact_fraction() {
+        let test_expression: Expr = (Expr::from(2) ^ -3) + Expr::from(7) % 3 - Expr::from(1) / 6;
+
+        let result = test_expression.eval_exact();
+        let expected_result = Ok(Rational::new(23, 24));
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn eval_exact_root() {
+        let test_expression: Expr = (Expr::from(4) / 9) ^ (Expr::from(1) / 2);
+
+        let result = test_expression.eval_exact();
+        let expected_result = Ok(Rational::new(2, 3));
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn eval_exact_irrational() {
+        let test_expression: Expr = Expr::from(2) ^ (Expr::from(1) / 2);
+
+        let result = test_expression.eval_exact();
+        let expected_result = Err(EvalError::new(EvalErrorKind::Irrational, &test_expression));
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn eval_exact_division_by_zero() {
+        let test_expression: Expr = Expr::from(2) / (Expr::from(1) - 1);
+
+        let result = test_expression.eval_exact();
+        let expected_result = Err(EvalError::new(EvalErrorKind::DivisionByZero, &test_expression));
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn eval_exact_factorial() {
+        let test_expression = (1..=30).fold(Expr::from(1), |product, factor| product * factor);
+
+        let result = test_expression.eval_exact();
+        let expected_result = Ok(Rational::from("265252859812191058636308480000000".parse::<Integer>().unwrap()));
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn eval_large_value() {
+        let result = Expr::from(u128::MAX).eval();
+        let expected_result = u128::MAX as f64;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn substitute() {
+        let test_expression: Expr = (Expr::var("x") ^ 2) + 3 * Expr::var("x") - Expr::var("y");
+
+        let result = test_expression.substitute("x", 2).eval_exact_with(&Environment::new().with("y", 1));
+        let expected_result = Ok(Rational::from(9));
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn contains_var() {
+        let test_expression: Expr = "2x + sin(y ^ 2)".parse().unwrap();
+
+        assert!(test_expression.contains_var("x"));
+        assert!(test_expression.contains_var("y"));
+        assert!(!test_expression.contains_var("z"));
+    }
+
+    #[test]
+    fn eval_with() {
+        let test_expression: Expr = (Expr::var("x") ^ 2) + 3 * Expr::var("x");
+
+        let results: Vec<f64> = [-1, 0, 2].iter()
+            .map(|x| test_expression.eval_with(&Environment::new().with("x", *x)).unwrap())
+            .collect();
+        let expected_results = vec![-2.0, 0.0, 10.0];
+
+        assert_eq!(results, expected_results);
+    }
+
+    #[test]
+    fn eval_with_unbound_variable() {
+        let test_expression: Expr = Expr::var("x") + Expr::var("y");
+
+        let result = test_expression.eval_with(&Environment::new().with("x", 1));
+        let expected_result = Err(EvalError::new(EvalErrorKind::UnboundVariable(String::from("y")), &Expr::var("y")));
+
+        assert_eq!(result, expected_result);
+        assert!(test_expression.eval().is_nan());
+    }
+
+    #[test]
+    fn eval_exact_with() {
+        let test_expression: Expr = Expr::var("x") / 3 + Expr::var("y");
+        let environment = Environment::new()
+            .with("x", 1)
+            .with("y", Expr::from(2) / 3);
+
+        let result = test_expression.eval_exact_with(&environment);
+        let expected_result = Ok(Rational::from(1));
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn cmp_exact() {
+        let large = Expr::from(Integer::from(10).pow(30));
+        let larger = Expr::from(Integer::from(10).pow(30) + Integer::one());
+        let third: Expr = Expr::from(1) / 3;
+
+        assert_eq!(large.cmp_exact(&larger), Ok(Ordering::Less));
+        assert_eq!(third.cmp_exact(&"0.3333333333333333".parse().unwrap()), Ok(Ordering::Greater));
+        assert_eq!(third.cmp_exact(&Expr::var("x")).unwrap_err().kind(), &EvalErrorKind::UnboundVariable(String::from("x")));
+    }
+
+    #[test]
+    fn exact_equality() {
+        let large = Expr::from(Integer::from(2).pow(100));
+        let larger = Expr::from(Integer::from(2).pow(100) + Integer::one());
+
+        assert!(large != larger);
+        assert!(large < larger);
+        assert_eq!("2 * 3".parse::<Expr>().unwrap(), Expr::from(6));
+    }
+
+    #[test]
+    fn incomparable() {
+        let nan = Expr::Div(Arc::new(Expr::from(0)), Arc::new(Expr::from(0)));
+
+        assert_eq!(nan.partial_cmp(&nan), None);
+        assert!(nan != nan);
+    }
+
+    #[test]
+    fn try_eval() {
+        let test_expression: Expr = "3 * x + 1".parse::<Expr>().unwrap().substitute("x", 2);
+
+        let result = test_expression.try_eval();
+        let expected_result = Ok(7.0);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn try_eval_division_by_zero() {
+        let test_expression: Expr = "1 + 2 / (x - x)".parse::<Expr>().unwrap().substitute("x", 3);
+
+        let error = test_expression.try_eval().unwrap_err();
+
+        assert_eq!(*error.kind(), EvalErrorKind::DivisionByZero);
+        assert_eq!(error.expression().to_string(), "2 / (3 - 3)");
+        assert_eq!(error.to_string(), "division by zero in `2 / (3 - 3)`");
+        assert!(test_expression.eval().is_infinite());
+    }
+
+    #[test]
+    fn try_eval_domain_error() {
+        let test_expression: Expr = "2 * (-4) ^ 0.5".parse().unwrap();
+
+        let error = test_expression.try_eval().unwrap_err();
+
+        assert_eq!(*error.kind(), EvalErrorKind::DomainError);
+        assert_eq!(error.expression().to_string(), "(-4) ^ (1 / 2)");
+        assert_eq!(test_expression.eval_exact().unwrap_err().kind(), &EvalErrorKind::DomainError);
+    }
+
+    #[test]
+    fn try_eval_overflow() {
+        let test_expression: Expr = "10 ^ 400 - 1".parse().unwrap();
+
+        let error = test_expression.try_eval().unwrap_err();
+
+        assert_eq!(*error.kind(), EvalErrorKind::Overflow);
+        assert_eq!(error.expression().to_string(), "10 ^ 400");
+    }
+
+    #[test]
+    #[should_panic(expected = "reserved for a constant")]
+    fn var_constant_name() {
+        Expr::var("pi");
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let test_expression: Expr = "log(2, x) + max(sin(x), 10 ^ 40)".parse().unwrap();
+
+        let json = serde_json::to_string(&test_expression).unwrap();
+        let result: Expr = serde_json::from_str(&json).unwrap();
+
+        assert!(json.contains("\"log\""));
+        assert!(result.structural_eq(&test_expression));
+    }
+
+    #[test]
+    fn try_eval_function() {
+        let test_expression: Expr = "1 + ln(x - 3)".parse::<Expr>().unwrap().substitute("x", 2);
+
+        let error = test_expression.try_eval().unwrap_err();
+
+        assert_eq!(*error.kind(), EvalErrorKind::DomainError);
+        assert_eq!(error.expression().to_string(), "ln(2 - 3)");
+        assert_eq!("sqrt(2) + abs(-1)".parse::<Expr>().unwrap().try_eval(), Ok(2_f64.sqrt() + 1.0));
+    }
+
+    #[test]
+    fn try_eval_wrong_arity() {
+        let test_expression = Expr::Fun(Function::Max, vec![Expr::var("x")].into());
+
+        let error = test_expression.eval_with(&Environment::new().with("x", 1)).unwrap_err();
+
+        assert_eq!(*error.kind(), EvalErrorKind::WrongArity);
+        assert_eq!(error.to_string(), "wrong number of arguments in `max(x)`");
+        assert_eq!(Expr::Fun(Function::Log, vec![Expr::from(2)].into()).eval_exact().unwrap_err().kind(), &EvalErrorKind::WrongArity);
+        assert!(test_expression.compile(&["x"]).unwrap().eval(&[1.0]).is_nan());
+        assert_eq!(test_expression.to_latex(), "\\operatorname{max}\\left(x\\right)");
+    }
+
+    #[test]
+    fn constants() {
+        let test_expression: Expr = "2pi + e".parse().unwrap();
+
+        assert_eq!(test_expression.try_eval(), Ok(2.0 * std::f64::consts::PI + std::f64::consts::E));
+        assert_eq!(test_expression.eval_exact().unwrap_err().kind(), &EvalErrorKind::Irrational);
+        assert_eq!(Expr::i().try_eval().unwrap_err().kind(), &EvalErrorKind::DomainError);
+    }
 }
@@ -0,0 +1,198 @@
+use std::thread;
+use crate::precise::expression::{ EvalError, Expr };
+use crate::precise::expression::compile::{ call, CompiledExpr, Instruction };
+
+const CHUNK: usize = 256;
+const MIN_ROWS_PER_WORKER: usize = 4096;
+
+fn validate(compiled: &CompiledExpr, columns: &[&[f64]], out: &[f64]) {
+    assert_eq!(columns.len(), compiled.variables.len(), "wrong number of columns for batch evaluation");
+    assert!(columns.iter().all(|column| column.len() == out.len()), "column length does not match output length");
+}
+
+fn binary(stack: &mut [f64], top: usize, rows: usize, operation: impl Fn(f64, f64) -> f64) {
+    let (lhs, rhs) = stack.split_at_mut((top - 1) * CHUNK);
+    let lhs = &mut lhs[(top - 2) * CHUNK..(top - 2) * CHUNK + rows];
+
+    for (x, y) in lhs.iter_mut().zip(rhs[..rows].iter()) {
+        *x = operation(*x, *y);
+    }
+}
+
+impl CompiledExpr {
+    pub fn eval_batch(&self, columns: &[&[f64]], out: &mut [f64]) {
+        validate(self, columns, out);
+
+        let mut stack = vec![0.0; self.depth * CHUNK];
+
+        for start in (0..out.len()).step_by(CHUNK) {
+            let end = (start + CHUNK).min(out.len());
+
+            self.run_chunk(columns, start, end, &mut stack);
+            out[start..end].copy_from_slice(&stack[..end - start]);
+        }
+    }
+
+    pub fn eval_batch_parallel(&self, columns: &[&[f64]], out: &mut [f64], workers: usize) {
+        validate(self, columns, out);
+
+        let workers = workers.min(out.len() / MIN_ROWS_PER_WORKER).max(1);
+
+        if workers == 1 {
+            return self.eval_batch(columns, out);
+        }
+
+        let rows_per_worker = out.len().div_ceil(workers);
+
+        thread::scope(|scope| {
+            for (index, out) in out.chunks_mut(rows_per_worker).enumerate() {
+                let start = index * rows_per_worker;
+                let columns: Vec<&[f64]> = columns.iter().map(|column| &column[start..start + out.len()]).collect();
+
+                scope.spawn(move || self.eval_batch(&columns, out));
+            }
+        });
+    }
+
+    fn run_chunk(&self, columns: &[&[f64]], start: usize, end: usize, stack: &mut [f64]) {
+        let rows = end - start;
+        let mut top = 0;
+
+        for instruction in self.instructions.iter() {
+            match *instruction {
+                Instruction::Const(value) => {
+                    stack[top * CHUNK..top * CHUNK + rows].iter_mut().for_each(|x| *x = value);
+                    top += 1;
+                },
+                Instruction::Var(index) => {
+                    stack[top * CHUNK..top * CHUNK + rows].copy_from_slice(&columns[index][start..end]);
+                    top += 1;
+                },
+                Instruction::Powi(exponent) => {
+                    stack[(top - 1) * CHUNK..(top - 1) * CHUNK + rows].iter_mut().for_each(|x| *x = x.powi(exponent));
+                },
+                Instruction::Call(function) if function.arity() == 1 => {
+                    stack[(top - 1) * CHUNK..(top - 1) * CHUNK + rows].iter_mut().for_each(|x| *x = call(function, &[*x]));
+                },
+                Instruction::Call(function) => {
+                    binary(stack, top, rows, |x, y| call(function, &[x, y]));
+                    top -= 1;
+                },
+                Instruction::Add => {
+                    binary(stack, top, rows, |x, y| x + y);
+                    top -= 1;
+                },
+                Instruction::Sub => {
+                    binary(stack, top, rows, |x, y| x - y);
+                    top -= 1;
+                },
+                Instruction::Mul => {
+                    binary(stack, top, rows, |x, y| x * y);
+                    top -= 1;
+                },
+                Instruction::Div => {
+                    binary(stack, top, rows, |x, y| x / y);
+                    top -= 1;
+                },
+                Instruction::Rem => {
+                    binary(stack, top, rows, |x, y| x % y);
+                    top -= 1;
+                },
+                Instruction::Pow => {
+                    binary(stack, top, rows, f64::powf);
+                    top -= 1;
+                },
+            }
+        }
+    }
+}
+
+impl Expr {
+    pub fn eval_batch(&self, variables: &[&str], columns: &[&[f64]], out: &mut [f64]) -> Result<(), EvalError> {
+        self.compile(variables)?.eval_batch(columns, out);
+
+        Ok(())
+    }
+
+    pub fn eval_batch_parallel(&self, variables: &[&str], columns: &[&[f64]], out: &mut [f64], workers: usize) -> Result<(), EvalError> {
+        self.compile(variables)?.eval_batch_parallel(columns, out, workers);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::precise::expression::Expr;
+
+    fn parse(source: &str) -> Expr {
+        source.parse().unwrap()
+    }
+
+    fn grid(rows: usize) -> (Vec<f64>, Vec<f64>) {
+        let x = (0..rows).map(|row| row as f64 / 100.0 - 3.0).collect();
+        let y = (0..rows).map(|row| (row % 17) as f64 * 0.25 + 0.5).collect();
+
+        (x, y)
+    }
+
+    #[test]
+    fn eval_batch() {
+        let expression = parse("x ^ 2 * y - sin(x) / y + max(x, y) + log(2, y) + 3");
+        let compiled = expression.compile(&["x", "y"]).unwrap();
+        let (x, y) = grid(1000);
+        let mut out = vec![0.0; 1000];
+
+        expression.eval_batch(&["x", "y"], &[&x, &y], &mut out).unwrap();
+
+        for row in 0..1000 {
+            assert_eq!(out[row], compiled.eval(&[x[row], y[row]]));
+        }
+    }
+
+    #[test]
+    fn eval_batch_parallel() {
+        let expression = parse("sqrt(abs(x)) * cos(y) - x % y");
+        let (x, y) = grid(50_000);
+
+        let mut sequential = vec![0.0; 50_000];
+        let mut parallel = vec![0.0; 50_000];
+
+        expression.eval_batch(&["x", "y"], &[&x, &y], &mut sequential).unwrap();
+        expression.eval_batch_parallel(&["x", "y"], &[&x, &y], &mut parallel, 4).unwrap();
+
+        assert_eq!(sequential, parallel);
+    }
+
+    #[test]
+    fn domain_errors() {
+        let mut out = vec![0.0; 3];
+
+        parse("ln(x)").eval_batch(&["x"], &[&[1.0, -1.0, 0.0]], &mut out).unwrap();
+
+        assert_eq!(out[0], 0.0);
+        assert!(out[1].is_nan() && out[2].is_nan());
+    }
+
+    #[test]
+    fn constant() {
+        let mut out = vec![0.0; 300];
+
+        parse("2 * 3").eval_batch(&[], &[], &mut out).unwrap();
+
+        assert!(out.iter().all(|value| *value == 6.0));
+    }
+
+    #[test]
+    fn unbound_variable() {
+        let mut out = vec![0.0; 2];
+
+        assert!(parse("x + z").eval_batch(&["x"], &[&[1.0, 2.0]], &mut out).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "column length")]
+    fn mismatched_columns() {
+        parse("x + y").eval_batch(&["x", "y"], &[&[1.0, 2.0], &[1.0]], &mut [0.0; 2]).unwrap();
+    }
+}
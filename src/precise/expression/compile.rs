@@ -0,0 +1,381 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use crate::precise::expression::{ EvalError, EvalErrorKind, Expr };
+use crate::precise::expression::environment::Environment;
+use crate::precise::expression::function::Function;
+
+const MAX_POWI: i32 = 64;
+const INLINE_STACK: usize = 32;
+
+thread_local! {
+    static SCRATCH: RefCell<Vec<f64>> = const { RefCell::new(Vec::new()) };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Instruction {
+    Const(f64),
+    Var(usize),
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+    Powi(i32),
+    Call(Function),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompiledExpr {
+    pub(crate) instructions: Vec<Instruction>,
+    pub(crate) variables: Vec<String>,
+    pub(crate) depth: usize,
+}
+
+struct Fragment {
+    constant: bool,
+    depth: usize,
+    code: VecDeque<Instruction>,
+}
+
+impl Fragment {
+    fn constant(value: f64) -> Fragment {
+        Fragment {
+            constant: true,
+            depth: 1,
+            code: VecDeque::from(vec![Instruction::Const(value)]),
+        }
+    }
+
+    fn value(&self) -> f64 {
+        match self.code.front() {
+            Some(Instruction::Const(value)) => *value,
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn concat(mut first: VecDeque<Instruction>, mut second: VecDeque<Instruction>) -> VecDeque<Instruction> {
+    if first.len() >= second.len() {
+        first.append(&mut second);
+        first
+    } else {
+        while let Some(instruction) = first.pop_back() {
+            second.push_front(instruction);
+        }
+
+        second
+    }
+}
+
+struct Compiler<'a> {
+    variables: &'a [&'a str],
+    fragments: Vec<Fragment>,
+}
+
+impl<'a> Compiler<'a> {
+    fn visit(&mut self, expression: &Expr) -> Result<(), EvalError> {
+        let children = self.fragments.split_off(self.fragments.len() - expression.arity());
+        let variable = match expression {
+            Expr::Var(x) => match self.variables.iter().position(|variable| variable == x) {
+                Some(index) => Some(index),
+                None => return Err(EvalError::new(EvalErrorKind::UnboundVariable(x.clone()), expression)),
+            },
+            _ => None,
+        };
+
+        let fragment = if variable.is_none() && children.iter().all(|child| child.constant) {
+            let values: Vec<f64> = children.iter().map(Fragment::value).collect();
+
+            Fragment::constant(expression.eval_float_node(&values, &Environment::new(), false).unwrap_or(f64::NAN))
+        } else {
+            self.emit(expression, variable, children)
+        };
+
+        self.fragments.push(fragment);
+
+        Ok(())
+    }
+
+    fn emit(&self, expression: &Expr, variable: Option<usize>, children: Vec<Fragment>) -> Fragment {
+        let mut children = children.into_iter();
+
+        let (depth, mut code, instruction) = match expression {
+            Expr::Var(_) => (1, VecDeque::new(), Instruction::Var(variable.unwrap())),
+            Expr::Fun(function, arguments) if arguments.len() != function.arity() => {
+                return Fragment::constant(f64::NAN);
+            },
+            Expr::Fun(function, _) => {
+                let (depth, code) = children.enumerate().fold((1, VecDeque::new()), |(depth, code), (index, argument)| {
+                    (depth.max(argument.depth + index), concat(code, argument.code))
+                });
+
+                (depth, code, Instruction::Call(*function))
+            },
+            _ => {
+                let (x, y) = (children.next().unwrap(), children.next().unwrap());
+
+                match expression {
+                    Expr::Add(_, _) | Expr::Mul(_, _) => {
+                        let depth = if x.depth == y.depth { x.depth + 1 } else { x.depth.max(y.depth) };
+                        let code = if y.depth > x.depth { concat(y.code, x.code) } else { concat(x.code, y.code) };
+                        let instruction = if let Expr::Add(_, _) = expression { Instruction::Add } else { Instruction::Mul };
+
+                        (depth, code, instruction)
+                    },
+                    Expr::Exp(_, exponent) => match integer_exponent(exponent) {
+                        Some(exponent) => (x.depth, x.code, Instruction::Powi(exponent)),
+                        None => (x.depth.max(y.depth + 1), concat(x.code, y.code), Instruction::Pow),
+                    },
+                    _ => {
+                        let instruction = match expression {
+                            Expr::Sub(_, _) => Instruction::Sub,
+                            Expr::Div(_, _) => Instruction::Div,
+                            _ => Instruction::Rem,
+                        };
+
+                        (x.depth.max(y.depth + 1), concat(x.code, y.code), instruction)
+                    },
+                }
+            },
+        };
+
+        code.push_back(instruction);
+
+        Fragment {
+            constant: false,
+            depth,
+            code,
+        }
+    }
+}
+
+fn integer_exponent(expression: &Expr) -> Option<i32> {
+    let exponent = match expression {
+        Expr::Val(x) => x.to_i128()?,
+        Expr::Mul(x, y) => match (x.as_ref(), y.as_ref()) {
+            (Expr::Val(x), Expr::Val(y)) => x.to_i128()?.checked_mul(y.to_i128()?)?,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    if exponent.abs() <= MAX_POWI as i128 {
+        Some(exponent as i32)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn call(function: Function, arguments: &[f64]) -> f64 {
+    function.eval(arguments).unwrap_or(f64::NAN)
+}
+
+impl CompiledExpr {
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    pub fn len(&self) -> usize {
+        self.instructions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty()
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn eval(&self, values: &[f64]) -> f64 {
+        if self.depth <= INLINE_STACK {
+            return self.run(values, &mut [0.0; INLINE_STACK]);
+        }
+
+        SCRATCH.with(|stack| self.eval_with_stack(values, &mut stack.borrow_mut()))
+    }
+
+    pub fn eval_with_stack(&self, values: &[f64], stack: &mut Vec<f64>) -> f64 {
+        if stack.len() < self.depth {
+            stack.resize(self.depth, 0.0);
+        }
+
+        self.run(values, stack)
+    }
+
+    fn run(&self, values: &[f64], stack: &mut [f64]) -> f64 {
+        assert_eq!(values.len(), self.variables.len(), "wrong number of values for compiled expression");
+
+        let mut top = 0;
+
+        for instruction in self.instructions.iter() {
+            match *instruction {
+                Instruction::Const(value) => {
+                    stack[top] = value;
+                    top += 1;
+                },
+                Instruction::Var(index) => {
+                    stack[top] = values[index];
+                    top += 1;
+                },
+                Instruction::Powi(exponent) => stack[top - 1] = stack[top - 1].powi(exponent),
+                Instruction::Call(function) => {
+                    let arity = function.arity();
+
+                    top -= arity;
+                    stack[top] = call(function, &stack[top..top + arity]);
+                    top += 1;
+                },
+                _ => {
+                    top -= 1;
+
+                    let (lhs, rhs) = (stack[top - 1], stack[top]);
+
+                    stack[top - 1] = match instruction {
+                        Instruction::Add => lhs + rhs,
+                        Instruction::Sub => lhs - rhs,
+                        Instruction::Mul => lhs * rhs,
+                        Instruction::Div => lhs / rhs,
+                        Instruction::Rem => lhs % rhs,
+                        _ => lhs.powf(rhs),
+                    };
+                },
+            }
+        }
+
+        stack[0]
+    }
+}
+
+impl Expr {
+    pub fn compile(&self, variables: &[&str]) -> Result<CompiledExpr, EvalError> {
+        let mut compiler = Compiler {
+            variables,
+            fragments: Vec::new(),
+        };
+
+        self.postorder(|expression| compiler.visit(expression))?;
+
+        let fragment = compiler.fragments.pop().unwrap();
+
+        Ok(CompiledExpr {
+            instructions: fragment.code.into(),
+            variables: variables.iter().map(|variable| variable.to_string()).collect(),
+            depth: fragment.depth,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use super::Instruction;
+    use crate::precise::expression::{ EvalErrorKind, Expr };
+    use crate::precise::expression::environment::Environment;
+
+    fn parse(source: &str) -> Expr {
+        source.parse().unwrap()
+    }
+
+    #[test]
+    fn constant_folding() {
+        let result = parse("x * (2 + 3) + sin(pi / 2)").compile(&["x"]).unwrap();
+
+        assert_eq!(result.len(), 5);
+        assert!(result.instructions.contains(&Instruction::Const(5.0)));
+        assert!(result.instructions.contains(&Instruction::Const(1.0)));
+        assert_eq!(result.eval(&[2.0]), 11.0);
+
+        let result = parse("2 ^ 10").compile(&[]).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result.eval(&[]), 1024.0);
+    }
+
+    #[test]
+    fn matches_tree_walker() {
+        let sources = [
+            "x ^ 3 - 2x * y + y / (x + 1)",
+            "sin(x) * cos(y) + ln(x ^ 2 + 1)",
+            "max(x, y) - min(x, y) + abs(x - y) % 3",
+            "sqrt(x ^ 2 + y ^ 2) ^ (1 / 3)",
+            "log(2, x + 5) * exp(-y) - floor(x) + ceil(y)",
+        ];
+
+        for source in sources.iter() {
+            let expression = parse(source);
+            let compiled = expression.compile(&["x", "y"]).unwrap();
+
+            for &(x, y) in [(0.5, 2.0), (3.0, -1.25), (-2.0, 7.0)].iter() {
+                let environment = Environment::new().with("x", x).with("y", y);
+                let expected = expression.eval_with(&environment).unwrap_or(f64::NAN);
+                let result = compiled.eval(&[x, y]);
+
+                if expected.is_nan() {
+                    assert!(result.is_nan(), "{} at ({}, {})", source, x, y);
+                } else {
+                    assert!((result - expected).abs() <= 1e-12 * expected.abs().max(1.0), "{} at ({}, {})", source, x, y);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn domain_errors() {
+        let result = parse("ln(x) + sqrt(y)").compile(&["x", "y"]).unwrap();
+
+        assert!(result.eval(&[-1.0, 4.0]).is_nan());
+        assert_eq!(result.eval(&[1.0, 4.0]), 2.0);
+
+        let error = parse("x + z").compile(&["x"]).unwrap_err();
+
+        assert_eq!(*error.kind(), EvalErrorKind::UnboundVariable(String::from("z")));
+        assert_eq!(error.expression().to_string(), "z");
+    }
+
+    #[test]
+    fn deep_stack() {
+        let mut expression = Expr::var("x");
+
+        for _ in 0..100 {
+            expression = Expr::Sub(Arc::new(Expr::from(1)), Arc::new(expression));
+        }
+
+        let result = expression.compile(&["x"]).unwrap();
+        let mut stack = Vec::new();
+
+        assert_eq!(result.depth(), 101);
+        assert_eq!(result.eval(&[3.0]), 3.0);
+        assert_eq!(result.eval_with_stack(&[3.0], &mut stack), 3.0);
+        assert_eq!(stack.len(), 101);
+
+        let capacity = stack.capacity();
+
+        assert_eq!(result.eval_with_stack(&[5.0], &mut stack), 5.0);
+        assert_eq!(stack.capacity(), capacity);
+    }
+
+    #[test]
+    fn long_chain() {
+        let chain = (1..200_000).fold(Expr::var("x"), |sum, term| sum + Expr::from(term as u64 % 7) * Expr::var("x"));
+        let result = chain.compile(&["x"]).unwrap();
+
+        assert_eq!(result.depth(), 3);
+        assert_eq!(result.eval(&[1.0]), chain.eval_with(&Environment::new().with("x", 1)).unwrap());
+
+        let constant = (1..200_000).fold(Expr::from(1), |product, term| Expr::from(term as u64 % 2) - product);
+
+        assert_eq!(constant.compile(&[]).unwrap().len(), 1);
+
+        let nested = (1..200_000).fold(Expr::var("x"), |difference, term| Expr::from(term as u64 % 7) - difference);
+
+        assert_eq!(nested.compile(&["x"]).unwrap().depth(), 200_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong number of values")]
+    fn wrong_arity() {
+        parse("x + y").compile(&["x", "y"]).unwrap().eval(&[1.0]);
+    }
+}
@@ -0,0 +1,70 @@
+use std::fmt;
+use serde_derive::{ Serialize, Deserialize };
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Constant {
+    Pi,
+    E,
+    I,
+}
+
+impl Constant {
+    pub const ALL: [Constant; 3] = [
+        Constant::Pi,
+        Constant::E,
+        Constant::I,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Constant> {
+        match name {
+            "π" => Some(Constant::Pi),
+            _ => Constant::ALL.iter()
+                .find(|constant| constant.name() == name)
+                .copied(),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Constant::Pi => "pi",
+            Constant::E => "e",
+            Constant::I => "i",
+        }
+    }
+
+    pub fn value(self) -> Option<f64> {
+        match self {
+            Constant::Pi => Some(std::f64::consts::PI),
+            Constant::E => Some(std::f64::consts::E),
+            Constant::I => None,
+        }
+    }
+}
+
+impl fmt::Display for Constant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Constant;
+
+    #[test]
+    fn from_name() {
+        for constant in Constant::ALL.iter() {
+            assert_eq!(Constant::from_name(constant.name()), Some(*constant));
+        }
+
+        assert_eq!(Constant::from_name("π"), Some(Constant::Pi));
+        assert_eq!(Constant::from_name("x"), None);
+    }
+
+    #[test]
+    fn value() {
+        assert_eq!(Constant::Pi.value(), Some(std::f64::consts::PI));
+        assert_eq!(Constant::I.value(), None);
+    }
+}
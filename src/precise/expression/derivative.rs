@@ -0,0 +1,184 @@
+use crate::precise::expression::Expr;
+use crate::precise::expression::function::Function;
+
+fn call(function: Function, argument: &Expr) -> Expr {
+    Expr::Fun(function, vec![argument.clone()].into())
+}
+
+fn differentiate(expression: &Expr, name: &str) -> Expr {
+    match expression {
+        Expr::Val(_) | Expr::Const(_) => Expr::from(0),
+        Expr::Var(x) => Expr::from(if x == name { 1 } else { 0 }),
+        Expr::Add(x, y) => differentiate(x, name) + differentiate(y, name),
+        Expr::Sub(x, y) => differentiate(x, name) - differentiate(y, name),
+        Expr::Mul(x, y) => differentiate(x, name) * (**y).clone() + (**x).clone() * differentiate(y, name),
+        Expr::Div(x, y) => {
+            let numerator = differentiate(x, name) * (**y).clone() - (**x).clone() * differentiate(y, name);
+
+            numerator / ((**y).clone() ^ Expr::from(2))
+        },
+        Expr::Rem(x, y) => {
+            let quotient = (**x).clone() / (**y).clone();
+            let truncated = quotient.clone() - quotient % Expr::from(1);
+
+            differentiate(x, name) - differentiate(y, name) * truncated
+        },
+        Expr::Exp(x, y) => power(x, y, name),
+        Expr::Fun(function, arguments) => chain(*function, arguments, name),
+    }
+}
+
+fn power(base: &Expr, exponent: &Expr, name: &str) -> Expr {
+    let power = base.clone() ^ exponent.clone();
+
+    match (base.contains_var(name), exponent.contains_var(name)) {
+        (false, false) => Expr::from(0),
+        (true, false) => exponent.clone() * (base.clone() ^ (exponent.clone() - Expr::from(1))) * differentiate(base, name),
+        (false, true) => power * call(Function::Ln, base) * differentiate(exponent, name),
+        (true, true) => {
+            let rate = differentiate(exponent, name) * call(Function::Ln, base) + exponent.clone() * differentiate(base, name) / base.clone();
+
+            power * rate
+        },
+    }
+}
+
+fn chain(function: Function, arguments: &[Expr], name: &str) -> Expr {
+    let x = &arguments[0];
+    let dx = differentiate(x, name);
+
+    match function {
+        Function::Sin => call(Function::Cos, x) * dx,
+        Function::Cos => Expr::from(-1) * call(Function::Sin, x) * dx,
+        Function::Tan => dx / (call(Function::Cos, x) ^ Expr::from(2)),
+        Function::Ln => dx / x.clone(),
+        Function::Log => {
+            let quotient = call(Function::Ln, &arguments[1]) / call(Function::Ln, x);
+
+            differentiate(&quotient, name)
+        },
+        Function::Exp => call(Function::Exp, x) * dx,
+        Function::Abs => x.clone() / call(Function::Abs, x) * dx,
+        Function::Floor | Function::Ceil => Expr::from(0),
+        Function::Min | Function::Max => {
+            let y = &arguments[1];
+            let dy = differentiate(y, name);
+            let sign = (x.clone() - y.clone()) / call(Function::Abs, &(x.clone() - y.clone()));
+            let spread = sign * (dx.clone() - dy.clone());
+
+            let sum = match function {
+                Function::Min => dx + dy - spread,
+                _ => dx + dy + spread,
+            };
+
+            sum / Expr::from(2)
+        },
+        Function::Sqrt => dx / (Expr::from(2) * call(Function::Sqrt, x)),
+    }
+}
+
+impl Expr {
+    pub fn derivative(&self, name: &str) -> Expr {
+        differentiate(self, name).simplify()
+    }
+
+    pub fn nth_derivative(&self, name: &str, order: u32) -> Expr {
+        (0..order).fold(self.simplify(), |expression, _| expression.derivative(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::precise::expression::Expr;
+    use crate::precise::expression::environment::Environment;
+
+    fn derivative(source: &str, name: &str) -> String {
+        source.parse::<Expr>().unwrap().derivative(name).to_string()
+    }
+
+    fn assert_derivative_eq(source: &str, expected: &str) {
+        let result = source.parse::<Expr>().unwrap().derivative("x");
+        let expected = expected.parse::<Expr>().unwrap().simplify();
+
+        for x in [0.3, 0.7, 1.9, 4.2].iter() {
+            let environment = Environment::new().with("x", *x);
+            let lhs = result.eval_with(&environment).unwrap();
+            let rhs = expected.eval_with(&environment).unwrap();
+
+            assert!((lhs - rhs).abs() < 1e-9, "d/dx {} = {}, expected {}", source, result, expected);
+        }
+    }
+
+    #[test]
+    fn polynomials() {
+        assert_eq!(derivative("3x ^ 2 + 2x - 7", "x"), "6 * x + 2");
+        assert_eq!(derivative("x ^ n", "x"), "n * x ^ (n - 1)");
+        assert_eq!(derivative("5", "x"), "0");
+    }
+
+    #[test]
+    fn product_and_quotient_rules() {
+        assert_derivative_eq("x * sin(x)", "sin(x) + x * cos(x)");
+        assert_derivative_eq("(x + 1) / (x - 1)", "-2 / (x - 1) ^ 2");
+    }
+
+    #[test]
+    fn chain_rule() {
+        assert_derivative_eq("sin(x ^ 2)", "2x cos(x ^ 2)");
+        assert_derivative_eq("exp(3x) + ln(2x)", "3exp(3x) + 1 / x");
+        assert_derivative_eq("sqrt(x ^ 2 + 1)", "x / sqrt(x ^ 2 + 1)");
+        assert_derivative_eq("tan(x) - cos(x)", "1 / cos(x) ^ 2 + sin(x)");
+        assert_derivative_eq("log(2, x)", "1 / (x * ln(2))");
+    }
+
+    #[test]
+    fn exponentials() {
+        assert_eq!(derivative("e ^ x", "x"), "e ^ x");
+        assert_derivative_eq("2 ^ x", "2 ^ x * ln(2)");
+        assert_derivative_eq("x ^ x", "x ^ x * (ln(x) + 1)");
+    }
+
+    #[test]
+    fn piecewise_functions() {
+        assert_derivative_eq("abs(x - 1)", "(x - 1) / abs(x - 1)");
+        assert_derivative_eq("max(x, 1)", "(1 + (x - 1) / abs(x - 1)) / 2");
+        assert_derivative_eq("min(x ^ 2, 1)", "(2x - (x ^ 2 - 1) / abs(x ^ 2 - 1) * 2x) / 2");
+        assert_eq!(derivative("floor(x) + 3", "x"), "0");
+    }
+
+    #[test]
+    fn remainder() {
+        let expression = "x % y".parse::<Expr>().unwrap();
+        let step = 1e-6;
+
+        for (x, y) in [(-7.0, 2.0), (7.0, -2.0), (-7.5, -2.0), (7.5, 2.0)].iter() {
+            let at = |y: f64| expression.eval_with(&Environment::new().with("x", *x).with("y", y)).unwrap();
+            let environment = Environment::new().with("x", *x).with("y", *y);
+
+            let result = expression.derivative("y").eval_with(&environment).unwrap();
+            let expected = (at(y + step) - at(y - step)) / (2.0 * step);
+
+            assert!((result - expected).abs() < 1e-6, "d/dy {} % {} = {}, expected {}", x, y, result, expected);
+        }
+
+        assert_eq!(expression.derivative("y").eval_with(&Environment::new().with("x", -7).with("y", 2)), Ok(3.0));
+    }
+
+    #[test]
+    fn partial_derivatives() {
+        assert_eq!(derivative("x ^ 2 * y + y ^ 3", "y"), "x ^ 2 + 3 * y ^ 2");
+        assert_eq!(derivative("x ^ 2 * y + y ^ 3", "x"), "2 * x * y");
+        assert_eq!(derivative("x * y * z", "z"), "x * y");
+        assert_eq!(derivative("a * x + b", "x"), "a");
+    }
+
+    #[test]
+    fn nth_derivative() {
+        let expression = "x ^ 4 + x ^ 2".parse::<Expr>().unwrap();
+
+        assert_eq!(expression.nth_derivative("x", 0).to_string(), "x ^ 4 + x ^ 2");
+        assert_eq!(expression.nth_derivative("x", 2).to_string(), "12 * x ^ 2 + 2");
+        assert_eq!(expression.nth_derivative("x", 5).to_string(), "0");
+        assert_eq!("sin(x)".parse::<Expr>().unwrap().nth_derivative("x", 4).to_string(), "sin(x)");
+    }
+}
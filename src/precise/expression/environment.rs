@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+use crate::precise::expression::Expr;
+
+#[derive(Debug, Clone, Default)]
+pub struct Environment {
+    bindings: HashMap<String, Expr>,
+}
+
+impl Environment {
+    pub fn new() -> Environment {
+        Environment {
+            bindings: HashMap::new(),
+        }
+    }
+
+    pub fn with<N: Into<String>, V: Into<Expr>>(mut self, name: N, value: V) -> Environment {
+        self.bind(name, value);
+        self
+    }
+
+    pub fn bind<N: Into<String>, V: Into<Expr>>(&mut self, name: N, value: V) -> Option<Expr> {
+        self.bindings.insert(name.into(), value.into())
+    }
+
+    pub fn unbind(&mut self, name: &str) -> Option<Expr> {
+        self.bindings.remove(name)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Expr> {
+        self.bindings.get(name)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.bindings.contains_key(name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.bindings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Environment;
+    use crate::precise::expression::Expr;
+
+    #[test]
+    fn bind() {
+        let mut environment = Environment::new();
+
+        let result1 = environment.bind("x", 3);
+        let result2 = environment.bind("x", 4);
+
+        assert_eq!(result1, None);
+        assert_eq!(result2, Some(Expr::from(3)));
+        assert_eq!(environment.get("x"), Some(&Expr::from(4)));
+    }
+
+    #[test]
+    fn with() {
+        let environment = Environment::new()
+            .with("x", 3)
+            .with("y", 4);
+
+        assert_eq!(environment.len(), 2);
+        assert!(environment.contains("y"));
+    }
+
+    #[test]
+    fn unbind() {
+        let mut environment = Environment::new().with("x", 3);
+
+        let result = environment.unbind("x");
+
+        assert_eq!(result, Some(Expr::from(3)));
+        assert!(environment.is_empty());
+    }
+}
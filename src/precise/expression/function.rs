@@ -0,0 +1,208 @@
+use std::fmt;
+use serde_derive::{ Serialize, Deserialize };
+use crate::precise::integer::Integer;
+use crate::precise::rational::Rational;
+
+const MAX_LOG_DENOMINATOR: i128 = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Function {
+    Sin,
+    Cos,
+    Tan,
+    Ln,
+    Log,
+    Exp,
+    Abs,
+    Floor,
+    Ceil,
+    Min,
+    Max,
+    Sqrt,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FunctionError {
+    Arity,
+    DomainError,
+    Irrational,
+}
+
+impl Function {
+    pub const ALL: [Function; 12] = [
+        Function::Sin,
+        Function::Cos,
+        Function::Tan,
+        Function::Ln,
+        Function::Log,
+        Function::Exp,
+        Function::Abs,
+        Function::Floor,
+        Function::Ceil,
+        Function::Min,
+        Function::Max,
+        Function::Sqrt,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Function> {
+        Function::ALL.iter()
+            .find(|function| function.name() == name)
+            .copied()
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Function::Sin => "sin",
+            Function::Cos => "cos",
+            Function::Tan => "tan",
+            Function::Ln => "ln",
+            Function::Log => "log",
+            Function::Exp => "exp",
+            Function::Abs => "abs",
+            Function::Floor => "floor",
+            Function::Ceil => "ceil",
+            Function::Min => "min",
+            Function::Max => "max",
+            Function::Sqrt => "sqrt",
+        }
+    }
+
+    pub fn arity(self) -> usize {
+        match self {
+            Function::Log | Function::Min | Function::Max => 2,
+            _ => 1,
+        }
+    }
+
+    pub(crate) fn eval(self, arguments: &[f64]) -> Result<f64, FunctionError> {
+        if arguments.len() != self.arity() {
+            return Err(FunctionError::Arity);
+        }
+
+        let x = arguments[0];
+
+        Ok(match self {
+            Function::Sin => x.sin(),
+            Function::Cos => x.cos(),
+            Function::Tan => x.tan(),
+            Function::Ln if x <= 0.0 => return Err(FunctionError::DomainError),
+            Function::Ln => x.ln(),
+            Function::Log => {
+                let y = arguments[1];
+
+                if x <= 0.0 || x == 1.0 || y <= 0.0 {
+                    return Err(FunctionError::DomainError);
+                }
+
+                y.ln() / x.ln()
+            },
+            Function::Exp => x.exp(),
+            Function::Abs => x.abs(),
+            Function::Floor => x.floor(),
+            Function::Ceil => x.ceil(),
+            Function::Min => x.min(arguments[1]),
+            Function::Max => x.max(arguments[1]),
+            Function::Sqrt if x < 0.0 => return Err(FunctionError::DomainError),
+            Function::Sqrt => x.sqrt(),
+        })
+    }
+
+    pub(crate) fn eval_exact(self, arguments: &[Rational]) -> Result<Rational, FunctionError> {
+        if arguments.len() != self.arity() {
+            return Err(FunctionError::Arity);
+        }
+
+        let x = &arguments[0];
+
+        match self {
+            Function::Sin | Function::Tan if x.is_zero() => Ok(Rational::zero()),
+            Function::Cos if x.is_zero() => Ok(Rational::one()),
+            Function::Ln | Function::Log if !x.is_positive() => Err(FunctionError::DomainError),
+            Function::Ln if x.is_one() => Ok(Rational::zero()),
+            Function::Log => log_exact(x, &arguments[1]),
+            Function::Exp if x.is_zero() => Ok(Rational::one()),
+            Function::Abs => Ok(x.abs()),
+            Function::Floor => Ok(x.floor()),
+            Function::Ceil => Ok(x.ceil()),
+            Function::Min => Ok(x.clone().min(arguments[1].clone())),
+            Function::Max => Ok(x.clone().max(arguments[1].clone())),
+            Function::Sqrt if x.is_negative() => Err(FunctionError::DomainError),
+            Function::Sqrt => x.root(&Integer::from(2)).ok_or(FunctionError::Irrational),
+            _ => Err(FunctionError::Irrational),
+        }
+    }
+}
+
+fn log_exact(base: &Rational, value: &Rational) -> Result<Rational, FunctionError> {
+    if base.is_one() || !value.is_positive() {
+        return Err(FunctionError::DomainError);
+    }
+
+    if value.is_one() {
+        return Ok(Rational::zero());
+    }
+
+    let estimate = value.to_f64().ln() / base.to_f64().ln();
+
+    for denominator in 1..=MAX_LOG_DENOMINATOR {
+        let numerator = (estimate * denominator as f64).round();
+
+        if !numerator.is_finite() || numerator == 0.0 || numerator.abs() > i64::MAX as f64 {
+            continue;
+        }
+
+        let candidate = Rational::new(numerator as i128, denominator);
+
+        let power = base.checked_pow(candidate.numerator());
+        let root = value.checked_pow(candidate.denominator());
+
+        if power.is_some() && power == root {
+            return Ok(candidate);
+        }
+    }
+
+    Err(FunctionError::Irrational)
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ Function, FunctionError };
+    use crate::precise::rational::Rational;
+
+    #[test]
+    fn from_name() {
+        for function in Function::ALL.iter() {
+            assert_eq!(Function::from_name(function.name()), Some(*function));
+        }
+
+        assert_eq!(Function::from_name("x"), None);
+    }
+
+    #[test]
+    fn eval() {
+        assert_eq!(Function::Log.eval(&[2.0, 8.0]), Ok(3.0));
+        assert_eq!(Function::Max.eval(&[2.0, 8.0]), Ok(8.0));
+        assert_eq!(Function::Sqrt.eval(&[-1.0]), Err(FunctionError::DomainError));
+        assert_eq!(Function::Ln.eval(&[0.0]), Err(FunctionError::DomainError));
+        assert_eq!(Function::Max.eval(&[2.0]), Err(FunctionError::Arity));
+        assert_eq!(Function::Sin.eval(&[]), Err(FunctionError::Arity));
+    }
+
+    #[test]
+    fn eval_exact() {
+        assert_eq!(Function::Log.eval_exact(&[Rational::from(4), Rational::from(8)]), Ok(Rational::new(3, 2)));
+        assert_eq!(Function::Log.eval_exact(&[Rational::new(1, 2), Rational::from(8)]), Ok(Rational::from(-3)));
+        assert_eq!(Function::Log.eval_exact(&[Rational::from(2), Rational::from(3)]), Err(FunctionError::Irrational));
+        assert_eq!(Function::Sqrt.eval_exact(&[Rational::new(9, 4)]), Ok(Rational::new(3, 2)));
+        assert_eq!(Function::Floor.eval_exact(&[Rational::new(-7, 2)]), Ok(Rational::from(-4)));
+        assert_eq!(Function::Sin.eval_exact(&[Rational::from(1)]), Err(FunctionError::Irrational));
+        assert_eq!(Function::Log.eval_exact(&[Rational::from(2)]), Err(FunctionError::Arity));
+    }
+}
@@ -0,0 +1,323 @@
+use std::sync::Arc;
+use crate::precise::algebra::polynomial::Polynomial;
+use crate::precise::expression::{ Expr, EvalErrorKind };
+use crate::precise::expression::constant::Constant;
+use crate::precise::expression::environment::Environment;
+use crate::precise::expression::function::Function;
+use crate::precise::expression::simplify::as_number;
+use crate::precise::rational::Rational;
+
+const SINGULARITY_SAMPLES: usize = 256;
+
+fn call(function: Function, argument: Expr) -> Expr {
+    Expr::Fun(function, vec![argument].into())
+}
+
+fn is_zero(expression: &Expr) -> bool {
+    matches!(expression, Expr::Val(x) if x.is_zero())
+}
+
+fn slope(expression: &Expr, name: &str) -> Option<Expr> {
+    let slope = expression.derivative(name);
+
+    if slope.contains_var(name) || is_zero(&slope) {
+        None
+    } else {
+        Some(slope)
+    }
+}
+
+fn invert(expression: &Expr) -> Expr {
+    match expression {
+        Expr::Exp(x, y) => Expr::Exp(x.clone(), Arc::new((Expr::from(-1) * (**y).clone()).simplify())),
+        _ => Expr::Exp(Arc::new(expression.clone()), Arc::new(Expr::from(-1))),
+    }
+}
+
+fn factors(expression: &Expr, inverted: bool, result: &mut Vec<Expr>) {
+    match expression {
+        Expr::Mul(x, y) => {
+            factors(x, inverted, result);
+            factors(y, inverted, result);
+        },
+        Expr::Div(x, y) => {
+            factors(x, inverted, result);
+            factors(y, !inverted, result);
+        },
+        _ if inverted => result.push(invert(expression)),
+        _ => result.push(expression.clone()),
+    }
+}
+
+fn integrate(expression: &Expr, name: &str) -> Option<Expr> {
+    if !expression.contains_var(name) {
+        return Some(expression.clone() * Expr::Var(name.to_string()));
+    }
+
+    match expression {
+        Expr::Add(x, y) => Some(integrate(x, name)? + integrate(y, name)?),
+        Expr::Sub(x, y) => Some(integrate(x, name)? - integrate(y, name)?),
+        Expr::Mul(_, _) | Expr::Div(_, _) => {
+            let mut result = Vec::new();
+            factors(expression, false, &mut result);
+
+            let (dependent, constant): (Vec<Expr>, Vec<Expr>) = result.into_iter().partition(|factor| factor.contains_var(name));
+
+            match dependent.as_slice() {
+                [factor] => {
+                    let coefficient = constant.into_iter().fold(Expr::from(1), |lhs, rhs| lhs * rhs);
+
+                    Some(coefficient * integrate(factor, name)?)
+                },
+                _ => None,
+            }
+        },
+        Expr::Exp(x, y) => power(x, y, name),
+        Expr::Fun(function, arguments) => elementary(*function, arguments, name),
+        Expr::Var(_) => power(expression, &Expr::from(1), name),
+        _ => None,
+    }
+}
+
+fn power(base: &Expr, exponent: &Expr, name: &str) -> Option<Expr> {
+    if exponent.contains_var(name) {
+        if base.contains_var(name) {
+            return None;
+        }
+
+        let slope = slope(exponent, name)?;
+        let power = base.clone() ^ exponent.clone();
+
+        return Some(match base {
+            Expr::Const(Constant::E) => power / slope,
+            _ => power / (slope * call(Function::Ln, base.clone())),
+        });
+    }
+
+    let slope = slope(base, name)?;
+
+    if as_number(exponent) == Some(Rational::from(-1)) {
+        return Some(call(Function::Ln, call(Function::Abs, base.clone())) / slope);
+    }
+
+    let exponent = (exponent.clone() + Expr::from(1)).simplify();
+
+    Some((base.clone() ^ exponent.clone()) / (exponent * slope))
+}
+
+fn elementary(function: Function, arguments: &[Expr], name: &str) -> Option<Expr> {
+    if function.arity() != 1 {
+        return None;
+    }
+
+    let x = arguments[0].clone();
+    let slope = slope(&x, name)?;
+
+    let antiderivative = match function {
+        Function::Sin => Expr::from(-1) * call(Function::Cos, x),
+        Function::Cos => call(Function::Sin, x),
+        Function::Tan => Expr::from(-1) * call(Function::Ln, call(Function::Abs, call(Function::Cos, x))),
+        Function::Ln => x.clone() * call(Function::Ln, x.clone()) - x,
+        Function::Exp => call(Function::Exp, x),
+        Function::Sqrt => Expr::from(2) * (x ^ (Expr::from(3) / Expr::from(2))) / Expr::from(3),
+        _ => return None,
+    };
+
+    Some(antiderivative / slope)
+}
+
+fn vanishing(expression: &Expr, result: &mut Vec<Expr>) {
+    expression.postorder::<(), _>(|node| {
+        match node {
+            Expr::Div(_, y) => result.push((**y).clone()),
+            Expr::Exp(x, y) if as_number(y).is_some_and(|y| y.is_negative()) => result.push((**x).clone()),
+            Expr::Fun(Function::Ln, arguments) | Expr::Fun(Function::Log, arguments) => result.extend(arguments.iter().cloned()),
+            Expr::Fun(Function::Tan, arguments) => result.extend(arguments.iter().map(|argument| call(Function::Cos, argument.clone()))),
+            _ => (),
+        }
+
+        Ok(())
+    }).unwrap();
+}
+
+fn simple_factors(expression: &Expr, result: &mut Vec<Expr>) {
+    match expression {
+        Expr::Mul(x, y) => {
+            simple_factors(x, result);
+            simple_factors(y, result);
+        },
+        Expr::Div(x, _) => simple_factors(x, result),
+        Expr::Exp(x, y) if as_number(y).is_some_and(|y| y.is_positive()) => simple_factors(x, result),
+        Expr::Fun(Function::Abs, arguments) => {
+            for argument in arguments.iter() {
+                simple_factors(argument, result);
+            }
+        },
+        _ => result.push(expression.clone()),
+    }
+}
+
+fn sample(lower: f64, upper: f64, index: usize) -> f64 {
+    lower + (upper - lower) * index as f64 / SINGULARITY_SAMPLES as f64
+}
+
+fn has_zero(expression: &Expr, name: &str, lower: f64, upper: f64) -> bool {
+    let polynomial = Polynomial::from_expr(expression, name)
+        .filter(|polynomial| !polynomial.is_constant())
+        .map(|polynomial| polynomial.div_rem(&polynomial.gcd(&polynomial.derivative())).0);
+
+    let mut previous: Option<f64> = None;
+
+    for index in 0..=SINGULARITY_SAMPLES {
+        let x = sample(lower, upper, index);
+
+        let value = match &polynomial {
+            Some(polynomial) => polynomial.eval_f64(x),
+            None => match expression.eval_with(&Environment::new().with(name, x)) {
+                Ok(value) => value,
+                Err(error) => return !matches!(error.kind(), EvalErrorKind::UnboundVariable(_)),
+            },
+        };
+
+        if value == 0.0 || !value.is_finite() || previous.is_some_and(|previous| previous.signum() != value.signum()) {
+            return true;
+        }
+
+        previous = Some(value);
+    }
+
+    false
+}
+
+fn is_singular(integrand: &Expr, antiderivative: &Expr, name: &str, lower: f64, upper: f64) -> bool {
+    let mut candidates = Vec::new();
+    vanishing(integrand, &mut candidates);
+    vanishing(antiderivative, &mut candidates);
+
+    let mut factors = Vec::new();
+
+    for candidate in candidates.iter() {
+        simple_factors(candidate, &mut factors);
+    }
+
+    let undefined = (0..=SINGULARITY_SAMPLES).any(|index| {
+        match integrand.eval_with(&Environment::new().with(name, sample(lower, upper, index))) {
+            Ok(_) => false,
+            Err(error) => !matches!(error.kind(), EvalErrorKind::UnboundVariable(_)),
+        }
+    });
+
+    undefined || factors.iter().any(|factor| factor.contains_var(name) && has_zero(factor, name, lower, upper))
+}
+
+impl Expr {
+    pub fn integrate(&self, name: &str) -> Option<Expr> {
+        integrate(&self.simplify(), name).map(|result| result.simplify())
+    }
+
+    pub fn definite_integral<L: Into<Expr>, U: Into<Expr>>(&self, name: &str, lower: L, upper: U) -> Option<Expr> {
+        let antiderivative = self.integrate(name)?;
+        let (lower, upper) = (lower.into(), upper.into());
+
+        if let (Ok(a), Ok(b)) = (lower.try_eval(), upper.try_eval()) {
+            if is_singular(self, &antiderivative, name, a.min(b), a.max(b)) {
+                return None;
+            }
+        }
+
+        let result = (antiderivative.substitute(name, upper) - antiderivative.substitute(name, lower)).simplify();
+
+        Some(match result.eval_exact() {
+            Ok(value) => Expr::from(value),
+            Err(_) => result,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::precise::expression::Expr;
+    use crate::precise::expression::environment::Environment;
+
+    fn integrate(source: &str) -> Option<String> {
+        source.parse::<Expr>().unwrap().integrate("x").map(|result| result.to_string())
+    }
+
+    fn assert_antiderivative(source: &str) {
+        let expression = source.parse::<Expr>().unwrap();
+        let antiderivative = expression.integrate("x").unwrap();
+        let derivative = antiderivative.derivative("x");
+
+        for x in [0.3, 0.7, 1.9, 4.2].iter() {
+            let environment = Environment::new().with("x", *x);
+            let lhs = derivative.eval_with(&environment).unwrap();
+            let rhs = expression.eval_with(&environment).unwrap();
+
+            assert!((lhs - rhs).abs() < 1e-9, "d/dx {} = {}, expected {}", antiderivative, derivative, source);
+        }
+    }
+
+    #[test]
+    fn polynomials() {
+        assert_eq!(integrate("3x ^ 2 + 2x - 7"), Some(String::from("x ^ 3 + x ^ 2 - 7 * x")));
+        assert_eq!(integrate("5"), Some(String::from("5 * x")));
+        assert_eq!(integrate("a * x"), Some(String::from("a * x ^ 2 / 2")));
+    }
+
+    #[test]
+    fn rational_powers() {
+        assert_eq!(integrate("1 / x"), Some(String::from("ln(abs(x))")));
+        assert_antiderivative("sqrt(x) + x ^ (2 / 3) - 2 / x ^ 3");
+        assert_antiderivative("(2x + 1) ^ 5 + 3 / (4x - 1)");
+    }
+
+    #[test]
+    fn elementary_functions() {
+        assert_eq!(integrate("cos(x)"), Some(String::from("sin(x)")));
+        assert_eq!(integrate("e ^ x"), Some(String::from("e ^ x")));
+        assert_antiderivative("sin(3x + 1) - 2cos(x / 2)");
+        assert_antiderivative("exp(2x) + 2 ^ x + ln(x)");
+        assert_antiderivative("tan(x) + sqrt(5x + 2)");
+    }
+
+    #[test]
+    fn unsupported() {
+        assert_eq!(integrate("sin(x ^ 2)"), None);
+        assert_eq!(integrate("x * exp(x)"), None);
+        assert_eq!(integrate("x ^ x"), None);
+        assert_eq!(integrate("floor(x)"), None);
+    }
+
+    #[test]
+    fn reserved_names() {
+        let expression = "x + 1".parse::<Expr>().unwrap();
+
+        assert_eq!(expression.integrate("i").map(|result| result.to_string()), Some(String::from("i * x + i")));
+    }
+
+    #[test]
+    fn definite_integral() {
+        let expression = "3x ^ 2 + 1".parse::<Expr>().unwrap();
+
+        assert_eq!(expression.definite_integral("x", 0, 2).unwrap().to_string(), "10");
+        assert_eq!("x".parse::<Expr>().unwrap().definite_integral("x", 0, Expr::from(1) / Expr::from(3)).unwrap().to_string(), "1 / 18");
+        assert_eq!("sin(x)".parse::<Expr>().unwrap().definite_integral("x", 0, Expr::pi()).unwrap().to_string(), "2");
+        assert_eq!("1 / x".parse::<Expr>().unwrap().definite_integral("x", 1, 2).unwrap().to_string(), "ln(2)");
+        assert_eq!("x".parse::<Expr>().unwrap().definite_integral("x", 0, "a".parse::<Expr>().unwrap()).unwrap().to_string(), "a ^ 2 / 2");
+        assert_eq!("1 / (x - a)".parse::<Expr>().unwrap().definite_integral("x", 3, 4).unwrap().to_string(), "-ln(abs(a - 3)) + ln(abs(a - 4))");
+    }
+
+    #[test]
+    fn divergent_integrals() {
+        let integral = |source: &str, lower: Expr, upper: Expr| source.parse::<Expr>().unwrap().definite_integral("x", lower, upper);
+
+        assert!(integral("1 / x ^ 2", Expr::from(-1), Expr::from(1)).is_none());
+        assert!(integral("1 / x", Expr::from(-1), Expr::from(1)).is_none());
+        assert!(integral("tan(x)", Expr::from(0), Expr::pi()).is_none());
+        assert!(integral("1 / x", Expr::from(0), Expr::from(1)).is_none());
+        assert!(integral("1 / (x ^ 2 - 2x + 1)", Expr::from(0), Expr::from(3)).is_none());
+        assert!(integral("sqrt(x)", Expr::from(-1), Expr::from(1)).is_none());
+        assert_eq!(integral("1 / x ^ 2", Expr::from(1), Expr::from(2)).unwrap().to_string(), "1 / 2");
+        assert!((integral("tan(x)", Expr::from(0), Expr::pi() / Expr::from(4)).unwrap().eval() - 2_f64.ln() / 2.0).abs() < 1e-15);
+    }
+}
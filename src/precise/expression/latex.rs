@@ -0,0 +1,222 @@
+use crate::precise::expression::Expr;
+use crate::precise::expression::constant::Constant;
+use crate::precise::expression::function::Function;
+use crate::precise::expression::print::{ self, Precedence };
+use crate::precise::expression::simplify::as_number;
+use crate::precise::integer::Integer;
+
+fn root_degree(exponent: &Expr) -> Option<Integer> {
+    let exponent = as_number(exponent)?;
+
+    if exponent.numerator().is_one() && !exponent.is_integer() {
+        Some(exponent.denominator().clone())
+    } else {
+        None
+    }
+}
+
+fn precedence(expression: &Expr) -> Precedence {
+    match expression {
+        Expr::Div(_, _) => Precedence::Power,
+        Expr::Exp(_, y) if root_degree(y).is_some() => Precedence::Primary,
+        _ => print::precedence(expression),
+    }
+}
+
+fn needs_parentheses(expression: &Expr, minimum: Precedence, leading: bool) -> bool {
+    let precedence = precedence(expression);
+
+    precedence < minimum || (precedence == Precedence::Unary && !leading)
+}
+
+fn variable(name: &str) -> String {
+    if name.chars().count() == 1 {
+        name.to_string()
+    } else {
+        format!("\\mathrm{{{}}}", name.replace('_', "\\_"))
+    }
+}
+
+fn juxtaposed(lhs: &Expr, rhs: &Expr) -> bool {
+    let starts_with_variable = match rhs {
+        Expr::Var(_) | Expr::Const(_) | Expr::Fun(_, _) => true,
+        Expr::Exp(x, y) => root_degree(y).is_none() && matches!(**x, Expr::Var(_) | Expr::Const(_) | Expr::Fun(_, _)),
+        _ => false,
+    };
+
+    match lhs {
+        Expr::Val(x) if !x.is_negative() => starts_with_variable || needs_parentheses(rhs, Precedence::Power, false),
+        _ => false,
+    }
+}
+
+enum Task<'a> {
+    Print(&'a Expr, bool),
+    Operand(&'a Expr, Precedence, bool),
+    Text(&'static str),
+    Owned(String),
+}
+
+fn latex(expression: &Expr) -> String {
+    let mut output = String::new();
+    let mut pending = vec![Task::Print(expression, true)];
+
+    while let Some(task) = pending.pop() {
+        match task {
+            Task::Print(expression, leading) => expand(expression, leading, &mut output, &mut pending),
+            Task::Operand(expression, minimum, leading) => {
+                if needs_parentheses(expression, minimum, leading) {
+                    sequence(&mut pending, vec![Task::Text("\\left("), Task::Print(expression, true), Task::Text("\\right)")]);
+                } else {
+                    pending.push(Task::Print(expression, leading));
+                }
+            },
+            Task::Text(text) => output.push_str(text),
+            Task::Owned(text) => output.push_str(&text),
+        }
+    }
+
+    output
+}
+
+fn expand<'a>(expression: &'a Expr, leading: bool, output: &mut String, pending: &mut Vec<Task<'a>>) {
+    if let Some(operand) = print::negated(expression) {
+        return sequence(pending, vec![Task::Text("-"), Task::Operand(operand, Precedence::Power, false)]);
+    }
+
+    match expression {
+        Expr::Val(x) => output.push_str(&x.to_string()),
+        Expr::Var(x) => output.push_str(&variable(x)),
+        Expr::Const(Constant::Pi) => output.push_str("\\pi"),
+        Expr::Const(x) => output.push_str(&x.to_string()),
+        Expr::Add(x, y) => binary(x, " + ", y, Precedence::Sum, leading, pending),
+        Expr::Sub(x, y) => binary(x, " - ", y, Precedence::Sum, leading, pending),
+        Expr::Mul(x, y) if juxtaposed(x, y) => binary(x, "", y, Precedence::Product, leading, pending),
+        Expr::Mul(x, y) => binary(x, " \\cdot ", y, Precedence::Product, leading, pending),
+        Expr::Div(x, y) => sequence(pending, vec![
+            Task::Text("\\frac{"),
+            Task::Print(x, true),
+            Task::Text("}{"),
+            Task::Print(y, true),
+            Task::Text("}"),
+        ]),
+        Expr::Rem(x, y) => binary(x, " \\bmod ", y, Precedence::Product, leading, pending),
+        Expr::Exp(x, y) => match root_degree(y) {
+            Some(ref degree) if *degree == Integer::from(2) => sequence(pending, vec![Task::Text("\\sqrt{"), Task::Print(x, true), Task::Text("}")]),
+            Some(degree) => sequence(pending, vec![Task::Owned(format!("\\sqrt[{}]{{", degree)), Task::Print(x, true), Task::Text("}")]),
+            None => sequence(pending, vec![
+                Task::Operand(x, Precedence::Primary, leading),
+                Task::Text("^{"),
+                Task::Print(y, true),
+                Task::Text("}"),
+            ]),
+        },
+        Expr::Fun(function, arguments) => call(*function, arguments, pending),
+    }
+}
+
+fn call<'a>(function: Function, arguments: &'a [Expr], pending: &mut Vec<Task<'a>>) {
+    let (open, close) = match function {
+        _ if arguments.len() != function.arity() => (format!("\\operatorname{{{}}}\\left(", function), "\\right)"),
+        Function::Log => {
+            return sequence(pending, vec![
+                Task::Text("\\log_{"),
+                Task::Print(&arguments[0], true),
+                Task::Text("}\\left("),
+                Task::Print(&arguments[1], true),
+                Task::Text("\\right)"),
+            ]);
+        },
+        Function::Abs => (String::from("\\left|"), "\\right|"),
+        Function::Floor => (String::from("\\left\\lfloor "), "\\right\\rfloor"),
+        Function::Ceil => (String::from("\\left\\lceil "), "\\right\\rceil"),
+        Function::Sqrt => (String::from("\\sqrt{"), "}"),
+        _ => (format!("\\{}\\left(", function), "\\right)"),
+    };
+
+    let mut tasks = vec![Task::Owned(open)];
+
+    for (index, argument) in arguments.iter().enumerate() {
+        if index > 0 {
+            tasks.push(Task::Text(", "));
+        }
+
+        tasks.push(Task::Print(argument, true));
+    }
+
+    tasks.push(Task::Text(close));
+    sequence(pending, tasks);
+}
+
+fn binary<'a>(lhs: &'a Expr, operator: &'static str, rhs: &'a Expr, precedence: Precedence, leading: bool, pending: &mut Vec<Task<'a>>) {
+    let (left, right) = match precedence {
+        Precedence::Sum => (Precedence::Sum, Precedence::Product),
+        _ => (Precedence::Product, Precedence::Power),
+    };
+
+    sequence(pending, vec![Task::Operand(lhs, left, leading), Task::Text(operator), Task::Operand(rhs, right, false)]);
+}
+
+fn sequence<'a>(pending: &mut Vec<Task<'a>>, tasks: Vec<Task<'a>>) {
+    pending.extend(tasks.into_iter().rev());
+}
+
+impl Expr {
+    pub fn to_latex(&self) -> String {
+        latex(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::precise::expression::Expr;
+
+    fn latex(source: &str) -> String {
+        source.parse::<Expr>().unwrap().to_latex()
+    }
+
+    #[test]
+    fn fractions() {
+        assert_eq!(latex("(3 + 2) * 15 / 61"), "\\frac{\\left(3 + 2\\right) \\cdot 15}{61}");
+        assert_eq!(latex("(1 / 2) ^ 2"), "\\left(\\frac{1}{2}\\right)^{2}");
+        assert_eq!(latex("x * (1 / y)"), "x \\cdot \\frac{1}{y}");
+    }
+
+    #[test]
+    fn powers_and_roots() {
+        assert_eq!(latex("x ^ (n + 1)"), "x^{n + 1}");
+        assert_eq!(latex("(x + 1) ^ 0.5"), "\\sqrt{x + 1}");
+        assert_eq!(latex("8 ^ (1 / 3)"), "\\sqrt[3]{8}");
+        assert_eq!(latex("(-2) ^ 2"), "\\left(-2\\right)^{2}");
+    }
+
+    #[test]
+    fn products() {
+        assert_eq!(latex("2x ^ 2 - 3(x - 1)"), "2x^{2} - 3\\left(x - 1\\right)");
+        assert_eq!(latex("x * y * 2"), "x \\cdot y \\cdot 2");
+        assert_eq!(latex("-x * (a - b)"), "-x \\cdot \\left(a - b\\right)");
+        assert_eq!(latex("a - -b"), "a - \\left(-b\\right)");
+    }
+
+    #[test]
+    fn modulo() {
+        assert_eq!(latex("(a + b) % 5"), "\\left(a + b\\right) \\bmod 5");
+    }
+
+    #[test]
+    fn functions() {
+        assert_eq!(latex("2sin(x) ^ 2 + log(2, x)"), "2\\sin\\left(x\\right)^{2} + \\log_{2}\\left(x\\right)");
+        assert_eq!(latex("abs(x - 1) * floor(x / 2)"), "\\left|x - 1\\right| \\cdot \\left\\lfloor \\frac{x}{2}\\right\\rfloor");
+        assert_eq!(latex("max(x, sqrt(y))"), "\\max\\left(x, \\sqrt{y}\\right)");
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(latex("2pi r + e ^ (i pi)"), "2\\pi \\cdot r + e^{i \\cdot \\pi}");
+    }
+
+    #[test]
+    fn variables() {
+        assert_eq!(latex("x_1 + theta"), "\\mathrm{x\\_1} + \\mathrm{theta}");
+    }
+}
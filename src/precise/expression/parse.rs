@@ -0,0 +1,621 @@
+use std::fmt;
+use std::convert::TryFrom;
+use std::str::FromStr;
+use std::sync::Arc;
+use crate::precise::expression::Expr;
+use crate::precise::expression::constant::Constant;
+use crate::precise::expression::function::Function;
+use crate::precise::expression::print::{ superscript, superscript_digit, is_superscript_minus };
+use crate::precise::integer::Integer;
+use crate::precise::rational::Rational;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Span {
+        Span {
+            start,
+            end,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    UnexpectedCharacter(char),
+    UnexpectedToken(String),
+    UnexpectedEnd,
+    UnclosedParenthesis,
+    UnmatchedParenthesis,
+    InvalidNumber,
+    WrongArgumentCount {
+        function: Function,
+        expected: usize,
+        found: usize,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    span: Span,
+}
+
+impl ParseError {
+    fn new(kind: ParseErrorKind, span: Span) -> ParseError {
+        ParseError {
+            kind,
+            span,
+        }
+    }
+
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    pub fn highlight(&self, source: &str) -> String {
+        let start = source.get(..self.span.start).map_or(0, |prefix| prefix.chars().count());
+        let length = source.get(self.span.start..self.span.end).map_or(0, |token| token.chars().count());
+
+        format!("{}\n{}{} {}", source, " ".repeat(start), "^".repeat(length.max(1)), self)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::UnexpectedCharacter(x) => write!(f, "unexpected character `{}`", x),
+            ParseErrorKind::UnexpectedToken(x) => write!(f, "unexpected `{}`", x),
+            ParseErrorKind::UnexpectedEnd => write!(f, "unexpected end of expression"),
+            ParseErrorKind::UnclosedParenthesis => write!(f, "unclosed parenthesis"),
+            ParseErrorKind::UnmatchedParenthesis => write!(f, "unmatched closing parenthesis"),
+            ParseErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ParseErrorKind::WrongArgumentCount { function, expected, found } => {
+                write!(f, "`{}` expects {} argument{}, found {}", function, expected, if *expected == 1 { "" } else { "s" }, found)
+            },
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(Rational),
+    Identifier(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    Caret,
+    Superscript(Integer),
+    LeftParenthesis,
+    RightParenthesis,
+    Comma,
+    Equals,
+    End,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Token::Number(x) => write!(f, "{}", x),
+            Token::Identifier(x) => write!(f, "{}", x),
+            Token::Plus => write!(f, "+"),
+            Token::Minus => write!(f, "-"),
+            Token::Star => write!(f, "*"),
+            Token::Slash => write!(f, "/"),
+            Token::Percent => write!(f, "%"),
+            Token::Caret => write!(f, "^"),
+            Token::Superscript(x) => write!(f, "{}", superscript(x)),
+            Token::LeftParenthesis => write!(f, "("),
+            Token::RightParenthesis => write!(f, ")"),
+            Token::Comma => write!(f, ","),
+            Token::Equals => write!(f, "="),
+            Token::End => write!(f, "end of expression"),
+        }
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<(Token, Span)>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut characters = source.char_indices().peekable();
+
+    while let Some((start, character)) = characters.next() {
+        let token = match character {
+            x if x.is_whitespace() => continue,
+            '+' => Token::Plus,
+            '-' | '\u{2212}' => Token::Minus,
+            '*' | '\u{00d7}' | '\u{22c5}' => Token::Star,
+            '/' | '\u{00f7}' => Token::Slash,
+            '%' => Token::Percent,
+            '^' => Token::Caret,
+            '(' => Token::LeftParenthesis,
+            ')' => Token::RightParenthesis,
+            ',' => Token::Comma,
+            '=' => Token::Equals,
+            x if x.is_ascii_digit() || x == '.' => {
+                let mut end = start + x.len_utf8();
+
+                while let Some(&(index, next)) = characters.peek() {
+                    if next.is_ascii_digit() || next == '.' {
+                        end = index + next.len_utf8();
+                        characters.next();
+                    } else {
+                        break;
+                    }
+                }
+
+                let span = Span::new(start, end);
+
+                match parse_number(&source[start..end]) {
+                    Some(number) => {
+                        tokens.push((Token::Number(number), span));
+                        continue;
+                    },
+                    None => return Err(ParseError::new(ParseErrorKind::InvalidNumber, span)),
+                }
+            },
+            x if superscript_digit(x).is_some() || is_superscript_minus(x) => {
+                let mut end = start + x.len_utf8();
+                let mut value = superscript_digit(x).map(Integer::from);
+
+                while let Some(&(index, next)) = characters.peek() {
+                    match superscript_digit(next) {
+                        Some(digit) => {
+                            value = Some(value.unwrap_or_else(Integer::zero) * Integer::from(10) + Integer::from(digit));
+                            end = index + next.len_utf8();
+                            characters.next();
+                        },
+                        None => break,
+                    }
+                }
+
+                let span = Span::new(start, end);
+
+                match value {
+                    Some(value) => {
+                        let value = if is_superscript_minus(x) { -value } else { value };
+
+                        tokens.push((Token::Superscript(value), span));
+                        continue;
+                    },
+                    None => return Err(ParseError::new(ParseErrorKind::UnexpectedCharacter(x), span)),
+                }
+            },
+            x if x.is_alphabetic() || x == '_' => {
+                let mut end = start + x.len_utf8();
+
+                while let Some(&(index, next)) = characters.peek() {
+                    if (next.is_alphanumeric() && superscript_digit(next).is_none()) || next == '_' {
+                        end = index + next.len_utf8();
+                        characters.next();
+                    } else {
+                        break;
+                    }
+                }
+
+                tokens.push((Token::Identifier(source[start..end].to_string()), Span::new(start, end)));
+                continue;
+            },
+            x => return Err(ParseError::new(ParseErrorKind::UnexpectedCharacter(x), Span::new(start, start + x.len_utf8()))),
+        };
+
+        tokens.push((token, Span::new(start, start + character.len_utf8())));
+    }
+
+    tokens.push((Token::End, Span::new(source.len(), source.len())));
+
+    Ok(tokens)
+}
+
+fn parse_number(source: &str) -> Option<Rational> {
+    let mut parts = source.splitn(2, '.');
+    let whole = parts.next().unwrap_or("");
+    let fraction = parts.next();
+
+    match fraction {
+        None => whole.parse::<Integer>().ok().map(Rational::from),
+        Some(fraction) => {
+            if fraction.is_empty() || fraction.contains('.') || !fraction.bytes().all(|digit| digit.is_ascii_digit()) {
+                return None;
+            }
+
+            let digits = format!("{}{}", whole, fraction);
+            let numerator = digits.parse::<Integer>().ok()?;
+            let denominator = Integer::from(10).pow(fraction.len() as u32);
+
+            Some(Rational::new(numerator, denominator))
+        },
+    }
+}
+
+struct Parser {
+    tokens: Vec<(Token, Span)>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.position].0
+    }
+
+    fn span(&self) -> Span {
+        self.tokens[self.position].1
+    }
+
+    fn advance(&mut self) -> (Token, Span) {
+        let token = self.tokens[self.position].clone();
+
+        if self.position < self.tokens.len() - 1 {
+            self.position += 1;
+        }
+
+        token
+    }
+
+    fn unexpected(&self) -> ParseError {
+        let kind = match self.peek() {
+            Token::End => ParseErrorKind::UnexpectedEnd,
+            Token::RightParenthesis => ParseErrorKind::UnmatchedParenthesis,
+            token => ParseErrorKind::UnexpectedToken(token.to_string()),
+        };
+
+        ParseError::new(kind, self.span())
+    }
+
+    fn parse(&mut self) -> Result<Expr, ParseError> {
+        let expression = self.parse_sum()?;
+
+        match self.peek() {
+            Token::End => Ok(expression),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn parse_equation(&mut self) -> Result<(Expr, Expr), ParseError> {
+        let lhs = self.parse_sum()?;
+
+        match self.peek() {
+            Token::Equals => self.advance(),
+            _ => return Err(self.unexpected()),
+        };
+
+        let rhs = self.parse()?;
+
+        Ok((lhs, rhs))
+    }
+
+    fn parse_sum(&mut self) -> Result<Expr, ParseError> {
+        let mut lhs = self.parse_product()?;
+
+        loop {
+            lhs = match self.peek() {
+                Token::Plus => {
+                    self.advance();
+                    Expr::Add(Arc::new(lhs), Arc::new(self.parse_product()?))
+                },
+                Token::Minus => {
+                    self.advance();
+                    Expr::Sub(Arc::new(lhs), Arc::new(self.parse_product()?))
+                },
+                _ => return Ok(lhs),
+            };
+        }
+    }
+
+    fn parse_product(&mut self) -> Result<Expr, ParseError> {
+        let mut lhs = self.parse_unary()?;
+
+        loop {
+            lhs = match self.peek() {
+                Token::Star => {
+                    self.advance();
+                    Expr::Mul(Arc::new(lhs), Arc::new(self.parse_unary()?))
+                },
+                Token::Slash => {
+                    self.advance();
+                    Expr::Div(Arc::new(lhs), Arc::new(self.parse_unary()?))
+                },
+                Token::Percent => {
+                    self.advance();
+                    Expr::Rem(Arc::new(lhs), Arc::new(self.parse_unary()?))
+                },
+                Token::Identifier(_) | Token::LeftParenthesis => {
+                    Expr::Mul(Arc::new(lhs), Arc::new(self.parse_power()?))
+                },
+                _ => return Ok(lhs),
+            };
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, ParseError> {
+        match self.peek() {
+            Token::Minus => {
+                self.advance();
+
+                Ok(match self.parse_unary()? {
+                    Expr::Val(ref x) => Expr::Val(-x),
+                    operand => Expr::Mul(Arc::new(Expr::from(-1)), Arc::new(operand)),
+                })
+            },
+            Token::Plus => {
+                self.advance();
+                self.parse_unary()
+            },
+            _ => self.parse_power(),
+        }
+    }
+
+    fn parse_power(&mut self) -> Result<Expr, ParseError> {
+        let base = self.parse_primary()?;
+
+        match self.peek() {
+            Token::Caret => {
+                self.advance();
+                Ok(Expr::Exp(Arc::new(base), Arc::new(self.parse_unary()?)))
+            },
+            Token::Superscript(x) => {
+                let exponent = Expr::Val(x.clone());
+                self.advance();
+
+                Ok(Expr::Exp(Arc::new(base), Arc::new(exponent)))
+            },
+            _ => Ok(base),
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, ParseError> {
+        match self.peek().clone() {
+            Token::Number(x) => {
+                self.advance();
+                Ok(Expr::from(x))
+            },
+            Token::Identifier(x) => {
+                let (_, span) = self.advance();
+
+                match (Function::from_name(&x), Constant::from_name(&x)) {
+                    (Some(function), _) if *self.peek() == Token::LeftParenthesis => self.parse_call(function, span),
+                    (_, Some(constant)) => Ok(Expr::Const(constant)),
+                    _ => Ok(Expr::Var(x)),
+                }
+            },
+            Token::LeftParenthesis => {
+                let (_, open) = self.advance();
+                let expression = self.parse_sum()?;
+
+                match self.peek() {
+                    Token::RightParenthesis => {
+                        self.advance();
+                        Ok(expression)
+                    },
+                    Token::End => Err(ParseError::new(ParseErrorKind::UnclosedParenthesis, open)),
+                    _ => Err(self.unexpected()),
+                }
+            },
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn parse_call(&mut self, function: Function, name: Span) -> Result<Expr, ParseError> {
+        let (_, open) = self.advance();
+        let mut arguments = vec![self.parse_sum()?];
+
+        loop {
+            match self.peek() {
+                Token::Comma => {
+                    self.advance();
+                    arguments.push(self.parse_sum()?);
+                },
+                Token::RightParenthesis => break,
+                Token::End => return Err(ParseError::new(ParseErrorKind::UnclosedParenthesis, open)),
+                _ => return Err(self.unexpected()),
+            }
+        }
+
+        let (_, close) = self.advance();
+
+        if arguments.len() != function.arity() {
+            let kind = ParseErrorKind::WrongArgumentCount {
+                function,
+                expected: function.arity(),
+                found: arguments.len(),
+            };
+
+            return Err(ParseError::new(kind, Span::new(name.start, close.end)));
+        }
+
+        Ok(Expr::Fun(function, arguments.into()))
+    }
+}
+
+impl FromStr for Expr {
+    type Err = ParseError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            tokens: tokenize(source)?,
+            position: 0,
+        };
+
+        parser.parse()
+    }
+}
+
+pub(crate) fn parse_equation(source: &str) -> Result<(Expr, Expr), ParseError> {
+    let mut parser = Parser {
+        tokens: tokenize(source)?,
+        position: 0,
+    };
+
+    parser.parse_equation()
+}
+
+impl TryFrom<&str> for Expr {
+    type Error = ParseError;
+
+    fn try_from(source: &str) -> Result<Self, Self::Error> {
+        source.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::{ ParseErrorKind, Span };
+    use crate::precise::expression::Expr;
+    use crate::precise::expression::function::Function;
+    use crate::precise::rational::Rational;
+
+    fn parse(source: &str) -> Expr {
+        source.parse().unwrap()
+    }
+
+    #[test]
+    fn integer() {
+        let result = parse("42");
+        let expected_result = Expr::from(42);
+
+        assert_eq!(format!("{:?}", result), format!("{:?}", expected_result));
+    }
+
+    #[test]
+    fn decimal() {
+        let result = parse("0.1").eval_exact();
+        let expected_result = Ok(Rational::new(1, 10));
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn large_integer() {
+        let result = parse("123456789012345678901234567890123456789 + 1").to_string();
+        let expected_result = "123456789012345678901234567890123456789 + 1";
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn precedence() {
+        let result = parse("4 * (2 + 3) - 6 / 3 ^ 2 % 5");
+        let expected_result = "4 * (2 + 3) - 6 / 3 ^ 2 % 5";
+
+        assert_eq!(result.to_string(), expected_result);
+    }
+
+    #[test]
+    fn associativity() {
+        assert_eq!(parse("8 - 3 - 2").to_string(), "8 - 3 - 2");
+        assert_eq!(parse("8 / 4 / 2").to_string(), "8 / 4 / 2");
+        assert_eq!(parse("2 ^ 3 ^ 2").to_string(), "2 ^ 3 ^ 2");
+    }
+
+    #[test]
+    fn unary_minus() {
+        assert_eq!(parse("-3").to_string(), "-3");
+        assert_eq!(parse("-3 ^ 2").eval_exact(), Ok(Rational::from(-9)));
+        assert_eq!(parse("(-3) ^ 2").eval_exact(), Ok(Rational::from(9)));
+        assert_eq!(parse("2 ^ -1").eval_exact(), Ok(Rational::new(1, 2)));
+        assert_eq!(parse("2 - -x").to_string(), "2 - (-x)");
+    }
+
+    #[test]
+    fn implicit_multiplication() {
+        assert_eq!(parse("x^2 + 3x").to_string(), "x ^ 2 + 3 * x");
+        assert_eq!(parse("2(x + 1)").to_string(), "2 * (x + 1)");
+    }
+
+    #[test]
+    fn superscripts() {
+        assert_eq!(parse("x² + 2x⁻¹").to_string(), "x ^ 2 + 2 * x ^ (-1)");
+        assert_eq!(parse("2³").eval_exact(), Ok(Rational::from(8)));
+    }
+
+    #[test]
+    fn functions() {
+        assert_eq!(parse("max(2, 7) - sqrt(16) + log(2, 8)").eval_exact(), Ok(Rational::from(6)));
+        assert_eq!(format!("{:?}", parse("sin x")), "Mul(Var(\"sin\"), Var(\"x\"))");
+        assert_eq!(parse("2sin(x)cos(x)").to_string(), "2 * sin(x) * cos(x)");
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(format!("{:?}", parse("2π + e")), "Add(Mul(Val(2), Const(Pi)), Const(E))");
+        assert_eq!(parse("pi").to_string(), "pi");
+    }
+
+    #[test]
+    fn wrong_argument_count() {
+        let error = "1 + log(8)".parse::<Expr>().unwrap_err();
+
+        assert_eq!(*error.kind(), ParseErrorKind::WrongArgumentCount { function: Function::Log, expected: 2, found: 1 });
+        assert_eq!(error.span(), Span::new(4, 10));
+        assert_eq!(error.to_string(), "`log` expects 2 arguments, found 1");
+    }
+
+    #[test]
+    fn try_from() {
+        let result = Expr::try_from("4 * (2 + 3)").unwrap().eval_exact();
+        let expected_result = Ok(Rational::from(20));
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn unexpected_character() {
+        let error = "2 + $".parse::<Expr>().unwrap_err();
+
+        assert_eq!(*error.kind(), ParseErrorKind::UnexpectedCharacter('$'));
+        assert_eq!(error.span(), Span::new(4, 5));
+    }
+
+    #[test]
+    fn unclosed_parenthesis() {
+        let error = "4 * (2 + 3".parse::<Expr>().unwrap_err();
+
+        assert_eq!(*error.kind(), ParseErrorKind::UnclosedParenthesis);
+        assert_eq!(error.span(), Span::new(4, 5));
+        assert_eq!(error.highlight("4 * (2 + 3"), "4 * (2 + 3\n    ^ unclosed parenthesis");
+    }
+
+    #[test]
+    fn unmatched_parenthesis() {
+        let error = "(2 + 3))".parse::<Expr>().unwrap_err();
+
+        assert_eq!(*error.kind(), ParseErrorKind::UnmatchedParenthesis);
+        assert_eq!(error.span(), Span::new(7, 8));
+    }
+
+    #[test]
+    fn unexpected_end() {
+        let error = "2 +".parse::<Expr>().unwrap_err();
+
+        assert_eq!(*error.kind(), ParseErrorKind::UnexpectedEnd);
+        assert_eq!(error.span(), Span::new(3, 3));
+    }
+
+    #[test]
+    fn unexpected_token() {
+        let error = "2 * * 3".parse::<Expr>().unwrap_err();
+
+        assert_eq!(*error.kind(), ParseErrorKind::UnexpectedToken(String::from("*")));
+        assert_eq!(error.span(), Span::new(4, 5));
+    }
+
+    #[test]
+    fn invalid_number() {
+        let error = "1.2.3 + 4".parse::<Expr>().unwrap_err();
+
+        assert_eq!(*error.kind(), ParseErrorKind::InvalidNumber);
+        assert_eq!(error.span(), Span::new(0, 5));
+    }
+}
@@ -0,0 +1,329 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use crate::precise::expression::{ Expr, EvalError };
+use crate::precise::expression::constant::Constant;
+use crate::precise::expression::environment::Environment;
+use crate::precise::expression::function::Function;
+use crate::precise::integer::Integer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+enum Visit<'a> {
+    Enter(&'a Expr),
+    Exit(&'a Expr),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Node {
+    Val(Integer),
+    Var(String),
+    Const(Constant),
+    Add(NodeId, NodeId),
+    Sub(NodeId, NodeId),
+    Mul(NodeId, NodeId),
+    Div(NodeId, NodeId),
+    Rem(NodeId, NodeId),
+    Exp(NodeId, NodeId),
+    Fun(Function, Vec<NodeId>),
+}
+
+impl Node {
+    pub fn children(&self) -> Vec<NodeId> {
+        match self {
+            Node::Val(_) | Node::Var(_) | Node::Const(_) => Vec::new(),
+            Node::Add(x, y) | Node::Sub(x, y) | Node::Mul(x, y) | Node::Div(x, y) | Node::Rem(x, y) | Node::Exp(x, y) => vec![*x, *y],
+            Node::Fun(_, arguments) => arguments.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ExprPool {
+    nodes: Vec<Node>,
+    ids: HashMap<Node, NodeId>,
+}
+
+impl ExprPool {
+    pub fn new() -> ExprPool {
+        ExprPool {
+            nodes: Vec::new(),
+            ids: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+
+    pub fn insert(&mut self, node: Node) -> NodeId {
+        if let Some(id) = self.ids.get(&node) {
+            return *id;
+        }
+
+        assert!(node.children().iter().all(|child| child.0 < self.nodes.len()), "node refers to an id outside the pool");
+
+        let id = NodeId(self.nodes.len());
+
+        self.nodes.push(node.clone());
+        self.ids.insert(node, id);
+
+        id
+    }
+
+    pub fn intern(&mut self, expression: &Expr) -> NodeId {
+        let mut seen: HashMap<*const Expr, NodeId> = HashMap::new();
+        let mut ids: Vec<NodeId> = Vec::new();
+        let mut pending = vec![Visit::Enter(expression)];
+
+        while let Some(item) = pending.pop() {
+            match item {
+                Visit::Enter(expression) => {
+                    if let Some(id) = seen.get(&(expression as *const Expr)) {
+                        ids.push(*id);
+                        continue;
+                    }
+
+                    pending.push(Visit::Exit(expression));
+
+                    match expression {
+                        Expr::Val(_) | Expr::Var(_) | Expr::Const(_) => (),
+                        Expr::Add(x, y) | Expr::Sub(x, y) | Expr::Mul(x, y) | Expr::Div(x, y) | Expr::Rem(x, y) | Expr::Exp(x, y) => {
+                            pending.push(Visit::Enter(y));
+                            pending.push(Visit::Enter(x));
+                        },
+                        Expr::Fun(_, arguments) => pending.extend(arguments.iter().rev().map(Visit::Enter)),
+                    }
+                },
+                Visit::Exit(expression) => {
+                    let node = match expression {
+                        Expr::Val(x) => Node::Val(x.clone()),
+                        Expr::Var(x) => Node::Var(x.clone()),
+                        Expr::Const(x) => Node::Const(*x),
+                        Expr::Fun(function, arguments) => Node::Fun(*function, ids.split_off(ids.len() - arguments.len())),
+                        _ => {
+                            let y = ids.pop().unwrap();
+                            let x = ids.pop().unwrap();
+
+                            match expression {
+                                Expr::Add(_, _) => Node::Add(x, y),
+                                Expr::Sub(_, _) => Node::Sub(x, y),
+                                Expr::Mul(_, _) => Node::Mul(x, y),
+                                Expr::Div(_, _) => Node::Div(x, y),
+                                Expr::Rem(_, _) => Node::Rem(x, y),
+                                _ => Node::Exp(x, y),
+                            }
+                        },
+                    };
+
+                    let id = self.insert(node);
+
+                    seen.insert(expression, id);
+                    ids.push(id);
+                },
+            }
+        }
+
+        ids.pop().unwrap()
+    }
+
+    pub fn to_expr(&self, id: NodeId) -> Expr {
+        let mut built: Vec<Option<Arc<Expr>>> = vec![None; id.0 + 1];
+
+        for index in self.reachable(&[id]) {
+            let child = |id: &NodeId| built[id.0].clone().unwrap();
+
+            let expression = match &self.nodes[index] {
+                Node::Val(x) => Expr::Val(x.clone()),
+                Node::Var(x) => Expr::Var(x.clone()),
+                Node::Const(x) => Expr::Const(*x),
+                Node::Add(x, y) => Expr::Add(child(x), child(y)),
+                Node::Sub(x, y) => Expr::Sub(child(x), child(y)),
+                Node::Mul(x, y) => Expr::Mul(child(x), child(y)),
+                Node::Div(x, y) => Expr::Div(child(x), child(y)),
+                Node::Rem(x, y) => Expr::Rem(child(x), child(y)),
+                Node::Exp(x, y) => Expr::Exp(child(x), child(y)),
+                Node::Fun(function, arguments) => Expr::Fun(*function, arguments.iter().map(|argument| (*child(argument)).clone()).collect()),
+            };
+
+            built[index] = Some(Arc::new(expression));
+        }
+
+        (*built[id.0].take().unwrap()).clone()
+    }
+
+    fn reachable(&self, roots: &[NodeId]) -> Vec<usize> {
+        let mut marked = vec![false; self.nodes.len()];
+        let mut pending: Vec<NodeId> = roots.to_vec();
+
+        while let Some(id) = pending.pop() {
+            if !marked[id.0] {
+                marked[id.0] = true;
+                pending.extend(self.nodes[id.0].children());
+            }
+        }
+
+        (0..self.nodes.len()).filter(|index| marked[*index]).collect()
+    }
+
+    pub fn eval(&self, id: NodeId, environment: &Environment) -> Result<f64, EvalError> {
+        Ok(self.eval_many(&[id], environment)?[0])
+    }
+
+    pub fn eval_many(&self, roots: &[NodeId], environment: &Environment) -> Result<Vec<f64>, EvalError> {
+        let mut values = vec![0.0; self.nodes.len()];
+
+        for index in self.reachable(roots) {
+            values[index] = self.eval_node(index, &values, environment)?;
+        }
+
+        Ok(roots.iter().map(|id| values[id.0]).collect())
+    }
+
+    fn eval_node(&self, index: usize, values: &[f64], environment: &Environment) -> Result<f64, EvalError> {
+        let node = &self.nodes[index];
+        let operands: Vec<f64> = node.children().iter().map(|child| values[child.0]).collect();
+
+        self.shallow(node).eval_float_node(&operands, environment, true).map_err(|error| match node {
+            Node::Val(_) | Node::Var(_) | Node::Const(_) => error,
+            _ => EvalError::new(error.kind().clone(), &self.to_expr(NodeId(index))),
+        })
+    }
+
+    fn shallow(&self, node: &Node) -> Expr {
+        let zero = Arc::new(Expr::from(0));
+        let operand = || zero.clone();
+
+        match node {
+            Node::Val(x) => Expr::Val(x.clone()),
+            Node::Var(x) => Expr::Var(x.clone()),
+            Node::Const(x) => Expr::Const(*x),
+            Node::Add(_, _) => Expr::Add(operand(), operand()),
+            Node::Sub(_, _) => Expr::Sub(operand(), operand()),
+            Node::Mul(_, _) => Expr::Mul(operand(), operand()),
+            Node::Div(_, _) => Expr::Div(operand(), operand()),
+            Node::Rem(_, _) => Expr::Rem(operand(), operand()),
+            Node::Exp(_, _) => Expr::Exp(operand(), operand()),
+            Node::Fun(function, arguments) => Expr::Fun(*function, vec![Expr::from(0); arguments.len()].into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ ExprPool, Node };
+    use crate::precise::algebra::linear::vector::vector3::Vector3;
+    use crate::precise::expression::{ Expr, EvalErrorKind };
+    use crate::precise::expression::environment::Environment;
+
+    fn parse(source: &str) -> Expr {
+        source.parse().unwrap()
+    }
+
+    #[test]
+    fn deduplicate() {
+        let mut pool = ExprPool::new();
+
+        let a = pool.intern(&parse("(x + 1) * (x + 1) + sin(x + 1)"));
+        let b = pool.intern(&parse("x + 1"));
+
+        assert_eq!(pool.len(), 6);
+
+        match pool.node(a) {
+            Node::Add(product, _) => assert_eq!(pool.node(*product), &Node::Mul(b, b)),
+            node => panic!("unexpected node {:?}", node),
+        }
+
+        assert_eq!(pool.intern(&parse("x + 1")), b);
+        assert_eq!(pool.len(), 6);
+    }
+
+    #[test]
+    fn to_expr() {
+        let mut pool = ExprPool::new();
+        let expression = parse("max(x, 2) ^ 2 - ln(y) / 3");
+        let id = pool.intern(&expression);
+
+        assert!(pool.to_expr(id).structural_eq(&expression));
+    }
+
+    #[test]
+    fn shared_evaluation() {
+        let mut expression = Expr::var("x");
+
+        for _ in 0..64 {
+            expression = &(&expression * &expression) / &expression;
+        }
+
+        let mut pool = ExprPool::new();
+        let id = pool.intern(&expression);
+
+        assert_eq!(pool.len(), 129);
+        assert_eq!(pool.eval(id, &Environment::new().with("x", 1.5)), Ok(1.5));
+    }
+
+    #[test]
+    fn long_chain() {
+        let chain = (1..200_000).fold(Expr::var("x"), |sum, term| sum + Expr::from(term as u64 % 7));
+
+        let mut pool = ExprPool::new();
+        let id = pool.intern(&chain);
+
+        assert_eq!(pool.len(), 200_000 + 7);
+        assert_eq!(pool.eval(id, &Environment::new().with("x", 1)), Ok(599_995.0));
+        assert_eq!(pool.to_expr(id).to_string(), chain.to_string());
+    }
+
+    #[test]
+    fn eval_many() {
+        let vector = Vector3::from((Expr::var("x"), Expr::var("y"), Expr::var("x") + 1));
+        let norm = vector.norm();
+        let normalized = vector.normalize();
+
+        let mut pool = ExprPool::new();
+        let ids = [pool.intern(&norm), pool.intern(&normalized[0]), pool.intern(&normalized[2])];
+        let environment = Environment::new().with("x", 2).with("y", 6);
+
+        let result = pool.eval_many(&ids, &environment).unwrap();
+
+        assert_eq!(result[0], 7.0);
+        assert!((result[1] - 2.0 / 7.0).abs() < 1e-15);
+        assert!((result[2] - 3.0 / 7.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn errors() {
+        let mut pool = ExprPool::new();
+
+        let id = pool.intern(&parse("1 / (x - x)"));
+        let error = pool.eval(id, &Environment::new().with("x", 1)).unwrap_err();
+
+        assert_eq!(error.kind(), &EvalErrorKind::DivisionByZero);
+        assert_eq!(error.expression().to_string(), "1 / (x - x)");
+
+        let expression = parse("2 + ln(x - 3) * (-8) ^ (1 / 3)");
+        let id = pool.intern(&expression);
+        let environment = Environment::new().with("x", 4);
+
+        assert_eq!(pool.eval(id, &environment), expression.eval_with(&environment));
+
+        let id = pool.intern(&parse("x + z"));
+        let error = pool.eval(id, &Environment::new().with("x", 1)).unwrap_err();
+
+        assert_eq!(error.kind(), &EvalErrorKind::UnboundVariable("z".to_string()));
+    }
+}
@@ -0,0 +1,362 @@
+use crate::precise::expression::Expr;
+use crate::precise::integer::Integer;
+
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+const SUPERSCRIPT_MINUS: char = '⁻';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrintOptions {
+    spacing: bool,
+    implicit_multiplication: bool,
+    unicode_superscripts: bool,
+}
+
+impl PrintOptions {
+    pub fn new() -> PrintOptions {
+        PrintOptions {
+            spacing: true,
+            implicit_multiplication: false,
+            unicode_superscripts: false,
+        }
+    }
+
+    pub fn spacing(mut self, enabled: bool) -> PrintOptions {
+        self.spacing = enabled;
+        self
+    }
+
+    pub fn implicit_multiplication(mut self, enabled: bool) -> PrintOptions {
+        self.implicit_multiplication = enabled;
+        self
+    }
+
+    pub fn unicode_superscripts(mut self, enabled: bool) -> PrintOptions {
+        self.unicode_superscripts = enabled;
+        self
+    }
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        PrintOptions::new()
+    }
+}
+
+pub(crate) fn superscript(value: &Integer) -> String {
+    value.to_string()
+        .chars()
+        .map(|digit| match digit.to_digit(10) {
+            Some(digit) => SUPERSCRIPT_DIGITS[digit as usize],
+            None => SUPERSCRIPT_MINUS,
+        })
+        .collect()
+}
+
+pub(crate) fn superscript_digit(character: char) -> Option<u32> {
+    SUPERSCRIPT_DIGITS.iter()
+        .position(|&digit| digit == character)
+        .map(|digit| digit as u32)
+}
+
+pub(crate) fn is_superscript_minus(character: char) -> bool {
+    character == SUPERSCRIPT_MINUS
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum Precedence {
+    Sum,
+    Product,
+    Unary,
+    Power,
+    Primary,
+}
+
+pub(super) fn negated(expression: &Expr) -> Option<&Expr> {
+    match expression {
+        Expr::Mul(x, y) => match (&**x, &**y) {
+            (Expr::Val(_), Expr::Val(_)) => None,
+            (Expr::Val(x), y) if (-x).is_one() => Some(y),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+pub(super) fn precedence(expression: &Expr) -> Precedence {
+    if negated(expression).is_some() {
+        return Precedence::Unary;
+    }
+
+    match expression {
+        Expr::Val(x) if x.is_negative() => Precedence::Unary,
+        Expr::Val(_) | Expr::Var(_) | Expr::Const(_) | Expr::Fun(_, _) => Precedence::Primary,
+        Expr::Add(_, _) | Expr::Sub(_, _) => Precedence::Sum,
+        Expr::Mul(_, _) | Expr::Div(_, _) | Expr::Rem(_, _) => Precedence::Product,
+        Expr::Exp(_, _) => Precedence::Power,
+    }
+}
+
+fn needs_parentheses(expression: &Expr, minimum: Precedence, leading: bool) -> bool {
+    let precedence = precedence(expression);
+
+    precedence < minimum || (precedence == Precedence::Unary && !leading)
+}
+
+fn starts_with_parenthesis(expression: &Expr) -> bool {
+    match expression {
+        _ if needs_parentheses(expression, Precedence::Power, false) => true,
+        Expr::Exp(x, _) => needs_parentheses(x, Precedence::Primary, false),
+        _ => false,
+    }
+}
+
+fn starts_with_variable(expression: &Expr) -> bool {
+    match expression {
+        _ if needs_parentheses(expression, Precedence::Power, false) => false,
+        Expr::Var(_) | Expr::Const(_) | Expr::Fun(_, _) => true,
+        Expr::Exp(x, _) => matches!(**x, Expr::Var(_) | Expr::Const(_) | Expr::Fun(_, _)),
+        _ => false,
+    }
+}
+
+enum Task<'a> {
+    Print(&'a Expr, bool),
+    Operand(&'a Expr, Precedence, bool),
+    Text(&'static str),
+    Owned(String),
+}
+
+struct Printer<'a> {
+    options: &'a PrintOptions,
+}
+
+impl<'a> Printer<'a> {
+    fn print(&self, expression: &Expr, leading: bool) -> String {
+        let mut output = String::new();
+        let mut pending = vec![Task::Print(expression, leading)];
+
+        while let Some(task) = pending.pop() {
+            match task {
+                Task::Print(expression, leading) => self.expand(expression, leading, &mut output, &mut pending),
+                Task::Operand(expression, minimum, leading) => {
+                    if needs_parentheses(expression, minimum, leading) {
+                        sequence(&mut pending, vec![Task::Text("("), Task::Print(expression, true), Task::Text(")")]);
+                    } else {
+                        pending.push(Task::Print(expression, leading));
+                    }
+                },
+                Task::Text(text) => output.push_str(text),
+                Task::Owned(text) => output.push_str(&text),
+            }
+        }
+
+        output
+    }
+
+    fn expand<'b>(&self, expression: &'b Expr, leading: bool, output: &mut String, pending: &mut Vec<Task<'b>>) {
+        if let Some(operand) = negated(expression) {
+            return sequence(pending, vec![Task::Text("-"), Task::Operand(operand, Precedence::Power, false)]);
+        }
+
+        match expression {
+            Expr::Val(x) => output.push_str(&x.to_string()),
+            Expr::Var(x) => output.push_str(x),
+            Expr::Const(x) => output.push_str(&x.to_string()),
+            Expr::Add(x, y) => self.binary(x, "+", y, Precedence::Sum, leading, pending),
+            Expr::Sub(x, y) => self.binary(x, "-", y, Precedence::Sum, leading, pending),
+            Expr::Mul(x, y) if self.implicit(x, y) => {
+                let separator = if self.ends_with_identifier(x) && starts_with_variable(y) { " " } else { "" };
+
+                sequence(pending, vec![
+                    Task::Operand(x, Precedence::Product, leading),
+                    Task::Text(separator),
+                    Task::Operand(y, Precedence::Power, false),
+                ]);
+            },
+            Expr::Mul(x, y) => self.binary(x, "*", y, Precedence::Product, leading, pending),
+            Expr::Div(x, y) => self.binary(x, "/", y, Precedence::Product, leading, pending),
+            Expr::Rem(x, y) => self.binary(x, "%", y, Precedence::Product, leading, pending),
+            Expr::Exp(x, y) => match &**y {
+                Expr::Val(y) if self.options.unicode_superscripts => {
+                    sequence(pending, vec![Task::Operand(x, Precedence::Primary, leading), Task::Owned(superscript(y))]);
+                },
+                _ => self.binary(x, "^", y, Precedence::Power, leading, pending),
+            },
+            Expr::Fun(function, arguments) => {
+                let separator = if self.options.spacing { ", " } else { "," };
+                let mut tasks = vec![Task::Text(function.name()), Task::Text("(")];
+
+                for (index, argument) in arguments.iter().enumerate() {
+                    if index > 0 {
+                        tasks.push(Task::Text(separator));
+                    }
+
+                    tasks.push(Task::Print(argument, true));
+                }
+
+                tasks.push(Task::Text(")"));
+                sequence(pending, tasks);
+            },
+        }
+    }
+
+    fn binary<'b>(&self, lhs: &'b Expr, operator: &'static str, rhs: &'b Expr, precedence: Precedence, leading: bool, pending: &mut Vec<Task<'b>>) {
+        let (left, right) = match precedence {
+            Precedence::Sum => (Precedence::Sum, Precedence::Product),
+            Precedence::Product => (Precedence::Product, Precedence::Power),
+            _ => (Precedence::Primary, Precedence::Power),
+        };
+
+        let separator = if self.options.spacing { " " } else { "" };
+
+        sequence(pending, vec![
+            Task::Operand(lhs, left, leading),
+            Task::Text(separator),
+            Task::Text(operator),
+            Task::Text(separator),
+            Task::Operand(rhs, right, false),
+        ]);
+    }
+
+    fn implicit(&self, lhs: &Expr, rhs: &Expr) -> bool {
+        if !self.options.implicit_multiplication {
+            return false;
+        }
+
+        if starts_with_parenthesis(rhs) {
+            !self.ends_with_identifier(lhs)
+        } else {
+            starts_with_variable(rhs)
+        }
+    }
+
+    fn ends_with_identifier(&self, expression: &Expr) -> bool {
+        let mut expression = expression;
+
+        loop {
+            let (rhs, minimum) = match expression {
+                Expr::Var(_) | Expr::Const(_) => return true,
+                Expr::Val(_) | Expr::Fun(_, _) => return false,
+                Expr::Exp(_, y) if self.options.unicode_superscripts && matches!(**y, Expr::Val(_)) => return false,
+                Expr::Add(_, y) | Expr::Sub(_, y) => (y, Precedence::Product),
+                Expr::Mul(_, y) | Expr::Div(_, y) | Expr::Rem(_, y) | Expr::Exp(_, y) => (y, Precedence::Power),
+            };
+
+            if needs_parentheses(rhs, minimum, false) {
+                return false;
+            }
+
+            expression = rhs;
+        }
+    }
+}
+
+fn sequence<'b>(pending: &mut Vec<Task<'b>>, tasks: Vec<Task<'b>>) {
+    pending.extend(tasks.into_iter().rev());
+}
+
+impl Expr {
+    pub fn to_string_with(&self, options: &PrintOptions) -> String {
+        Printer {
+            options,
+        }.print(self, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PrintOptions;
+    use crate::precise::expression::Expr;
+
+    fn parse(source: &str) -> Expr {
+        source.parse().unwrap()
+    }
+
+    fn assert_round_trip(source: &str, options: &PrintOptions) {
+        let expression = parse(source);
+        let printed = expression.to_string_with(options);
+
+        assert_eq!(format!("{:?}", parse(&printed)), format!("{:?}", expression), "{} printed as {}", source, printed);
+    }
+
+    #[test]
+    fn minimal_parentheses() {
+        assert_eq!(parse("((3 + 2) * (15 / 61))").to_string(), "(3 + 2) * (15 / 61)");
+        assert_eq!(parse("(3 + 2) * 15 / 61").to_string(), "(3 + 2) * 15 / 61");
+        assert_eq!(parse("(a - b) - (c - d)").to_string(), "a - b - (c - d)");
+        assert_eq!(parse("(2 ^ 3) ^ 2").to_string(), "(2 ^ 3) ^ 2");
+        assert_eq!(parse("2 ^ (3 ^ 2)").to_string(), "2 ^ 3 ^ 2");
+    }
+
+    #[test]
+    fn negation() {
+        assert_eq!(parse("-x * y + -3").to_string(), "-x * y + (-3)");
+        assert_eq!(parse("-(x + y)").to_string(), "-(x + y)");
+        assert_eq!(parse("(-3) ^ 2").to_string(), "(-3) ^ 2");
+        assert_eq!(parse("-3 ^ 2").to_string(), "-3 ^ 2");
+        assert_eq!(parse("2 ^ -1").to_string(), "2 ^ (-1)");
+    }
+
+    #[test]
+    fn spacing() {
+        let options = PrintOptions::new().spacing(false);
+
+        assert_eq!(parse("(x + 1) * (x - 1) / 2").to_string_with(&options), "(x+1)*(x-1)/2");
+    }
+
+    #[test]
+    fn implicit_multiplication() {
+        let options = PrintOptions::new().implicit_multiplication(true);
+
+        assert_eq!(parse("3 * x ^ 2 + 2 * x * y").to_string_with(&options), "3x ^ 2 + 2x y");
+        assert_eq!(parse("(x + 1) * (x - 1) * 2").to_string_with(&options), "(x + 1)(x - 1) * 2");
+    }
+
+    #[test]
+    fn unicode_superscripts() {
+        let options = PrintOptions::new().implicit_multiplication(true).unicode_superscripts(true);
+
+        assert_eq!(parse("3 * x ^ 2 - x ^ -1 + (x ^ 2) ^ 3").to_string_with(&options), "3x² - x⁻¹ + (x²)³");
+    }
+
+    #[test]
+    fn functions() {
+        let options = PrintOptions::new().implicit_multiplication(true).unicode_superscripts(true);
+
+        assert_eq!(parse("log(2, x) + sin(x)^2").to_string(), "log(2, x) + sin(x) ^ 2");
+        assert_eq!(parse("2 * sin(x) ^ 2 * x * (x + 1)").to_string_with(&options), "2sin(x)²x * (x + 1)");
+        assert_eq!(parse("min(x,y)").to_string_with(&PrintOptions::new().spacing(false)), "min(x,y)");
+    }
+
+    #[test]
+    fn round_trip() {
+        let sources = [
+            "(3 + 2) * (15 / 61)",
+            "a - (b - c) + (d + e)",
+            "a / (b * c) % d",
+            "-x * -y - -(z ^ 2)",
+            "-1 * 3 + 2 * (-1 * 3)",
+            "2 ^ -x ^ 2",
+            "(-2) ^ 3 ^ (-4)",
+            "x y z + 2x(x + 1) ^ 2",
+            "(x ^ 2) ^ 3 * 2 ^ 10 * x2 y",
+            "(a b) ^ 2 * (a + b)(a - b)",
+            "x * (y + 1) + sin(x) ^ 2 * 2cos(x)",
+            "log(2, x + 1) * max(-x, 3) ^ -1 - sqrt(x)(x + 1)",
+            "2pi r + e ^ (i pi) + π",
+        ];
+
+        let options = [
+            PrintOptions::new(),
+            PrintOptions::new().spacing(false),
+            PrintOptions::new().implicit_multiplication(true),
+            PrintOptions::new().spacing(false).implicit_multiplication(true).unicode_superscripts(true),
+        ];
+
+        for source in sources.iter() {
+            for options in options.iter() {
+                assert_round_trip(source, options);
+            }
+        }
+    }
+}
@@ -0,0 +1,377 @@
+use std::fmt;
+use crate::precise::expression::{ Expr, EvalError };
+use crate::precise::expression::environment::Environment;
+
+const MAX_SIMPSON_DEPTH: u32 = 50;
+const MAX_SIMPSON_EVALUATIONS: usize = 100_000;
+const MAX_INTERVALS: usize = 1000;
+const ENDPOINT_OFFSET: f64 = 1e-12;
+
+const KRONROD_NODES: [f64; 8] = [
+    0.991_455_371_120_812_6,
+    0.949_107_912_342_758_5,
+    0.864_864_423_359_769_1,
+    0.741_531_185_599_394_4,
+    0.586_087_235_467_691_1,
+    0.405_845_151_377_397_2,
+    0.207_784_955_007_898_5,
+    0.0,
+];
+
+const KRONROD_WEIGHTS: [f64; 8] = [
+    0.022_935_322_010_529_22,
+    0.063_092_092_629_978_55,
+    0.104_790_010_322_250_18,
+    0.140_653_259_715_525_92,
+    0.169_004_726_639_267_9,
+    0.190_350_578_064_785_4,
+    0.204_432_940_075_298_9,
+    0.209_482_141_084_727_83,
+];
+
+const GAUSS_WEIGHTS: [f64; 4] = [
+    0.129_484_966_168_869_7,
+    0.279_705_391_489_276_7,
+    0.381_830_050_505_118_9,
+    0.417_959_183_673_469_4,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quadrature {
+    value: f64,
+    error: f64,
+    evaluations: usize,
+}
+
+impl Quadrature {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn error(&self) -> f64 {
+        self.error
+    }
+
+    pub fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuadratureErrorKind {
+    InvalidBounds,
+    InvalidTolerance,
+    Evaluation(EvalError),
+    NotConverged,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuadratureError {
+    kind: QuadratureErrorKind,
+    estimate: Option<Quadrature>,
+}
+
+impl QuadratureError {
+    fn new(kind: QuadratureErrorKind) -> QuadratureError {
+        QuadratureError {
+            kind,
+            estimate: None,
+        }
+    }
+
+    pub fn kind(&self) -> &QuadratureErrorKind {
+        &self.kind
+    }
+
+    pub fn estimate(&self) -> Option<&Quadrature> {
+        self.estimate.as_ref()
+    }
+}
+
+impl From<EvalError> for QuadratureError {
+    fn from(error: EvalError) -> Self {
+        QuadratureError::new(QuadratureErrorKind::Evaluation(error))
+    }
+}
+
+impl fmt::Display for QuadratureErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QuadratureErrorKind::InvalidBounds => write!(f, "integration bounds must be finite"),
+            QuadratureErrorKind::InvalidTolerance => write!(f, "tolerance must be positive"),
+            QuadratureErrorKind::Evaluation(error) => write!(f, "{}", error),
+            QuadratureErrorKind::NotConverged => write!(f, "integration did not reach the requested tolerance"),
+        }
+    }
+}
+
+impl fmt::Display for QuadratureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.estimate {
+            Some(estimate) => write!(f, "{} (estimated error {})", self.kind, estimate.error),
+            None => write!(f, "{}", self.kind),
+        }
+    }
+}
+
+impl std::error::Error for QuadratureError {}
+
+struct Integrand<'a> {
+    expression: &'a Expr,
+    name: &'a str,
+    environment: Environment,
+    evaluations: usize,
+}
+
+impl<'a> Integrand<'a> {
+    fn new(expression: &'a Expr, name: &'a str) -> Integrand<'a> {
+        Integrand {
+            expression,
+            name,
+            environment: Environment::new(),
+            evaluations: 0,
+        }
+    }
+
+    fn eval(&mut self, x: f64) -> Result<f64, EvalError> {
+        self.evaluations += 1;
+        self.environment.bind(self.name, x);
+        self.expression.eval_with(&self.environment)
+    }
+
+    fn eval_endpoint(&mut self, x: f64, toward: f64) -> Result<f64, EvalError> {
+        match self.eval(x) {
+            Ok(value) => Ok(value),
+            Err(_) => self.eval(x + (toward - x) * ENDPOINT_OFFSET),
+        }
+    }
+
+    fn simpson(&mut self, a: f64, b: f64, values: (f64, f64, f64), whole: f64, tolerance: f64, depth: u32) -> Result<(f64, f64), EvalError> {
+        let (fa, fm, fb) = values;
+        let m = (a + b) / 2.0;
+        let (lm, rm) = ((a + m) / 2.0, (m + b) / 2.0);
+        let (flm, frm) = (self.eval(lm)?, self.eval(rm)?);
+
+        let left = (m - a) / 6.0 * (fa + 4.0 * flm + fm);
+        let right = (b - m) / 6.0 * (fm + 4.0 * frm + fb);
+        let delta = left + right - whole;
+
+        if delta.abs() <= 15.0 * tolerance || depth == 0 || self.evaluations >= MAX_SIMPSON_EVALUATIONS || lm <= a || rm >= b {
+            return Ok((left + right + delta / 15.0, delta.abs() / 15.0));
+        }
+
+        let (lhs, lhs_error) = self.simpson(a, m, (fa, flm, fm), left, tolerance / 2.0, depth - 1)?;
+        let (rhs, rhs_error) = self.simpson(m, b, (fm, frm, fb), right, tolerance / 2.0, depth - 1)?;
+
+        Ok((lhs + rhs, lhs_error + rhs_error))
+    }
+
+    fn kronrod(&mut self, a: f64, b: f64) -> Result<(f64, f64), EvalError> {
+        let center = (a + b) / 2.0;
+        let radius = (b - a) / 2.0;
+
+        let mut kronrod = 0.0;
+        let mut gauss = 0.0;
+
+        for (index, (node, weight)) in KRONROD_NODES.iter().zip(KRONROD_WEIGHTS.iter()).enumerate() {
+            let values = if *node == 0.0 {
+                self.eval(center)?
+            } else {
+                self.eval(center - radius * node)? + self.eval(center + radius * node)?
+            };
+
+            kronrod += weight * values;
+
+            if index % 2 == 1 {
+                gauss += GAUSS_WEIGHTS[index / 2] * values;
+            }
+        }
+
+        Ok((kronrod * radius, (kronrod - gauss).abs() * radius))
+    }
+}
+
+fn validate(lower: f64, upper: f64, tolerance: f64) -> Result<(), QuadratureError> {
+    if !lower.is_finite() || !upper.is_finite() {
+        return Err(QuadratureError::new(QuadratureErrorKind::InvalidBounds));
+    }
+
+    if tolerance.is_nan() || tolerance <= 0.0 {
+        return Err(QuadratureError::new(QuadratureErrorKind::InvalidTolerance));
+    }
+
+    Ok(())
+}
+
+fn oriented(lower: f64, upper: f64, value: f64) -> f64 {
+    if lower > upper { -value } else { value }
+}
+
+fn finish(value: f64, error: f64, evaluations: usize, converged: bool) -> Result<Quadrature, QuadratureError> {
+    let result = Quadrature {
+        value,
+        error,
+        evaluations,
+    };
+
+    if converged {
+        Ok(result)
+    } else {
+        Err(QuadratureError {
+            kind: QuadratureErrorKind::NotConverged,
+            estimate: Some(result),
+        })
+    }
+}
+
+impl Expr {
+    pub fn integrate_simpson(&self, name: &str, lower: f64, upper: f64, tolerance: f64) -> Result<Quadrature, QuadratureError> {
+        validate(lower, upper, tolerance)?;
+
+        let (a, b) = (lower.min(upper), lower.max(upper));
+
+        if a == b {
+            return finish(0.0, 0.0, 0, true);
+        }
+
+        let mut integrand = Integrand::new(self, name);
+
+        let fa = integrand.eval_endpoint(a, b)?;
+        let fb = integrand.eval_endpoint(b, a)?;
+        let fm = integrand.eval((a + b) / 2.0)?;
+        let whole = (b - a) / 6.0 * (fa + 4.0 * fm + fb);
+
+        let (value, error) = integrand.simpson(a, b, (fa, fm, fb), whole, tolerance, MAX_SIMPSON_DEPTH)?;
+
+        let converged = error <= tolerance && integrand.evaluations < MAX_SIMPSON_EVALUATIONS;
+
+        finish(oriented(lower, upper, value), error, integrand.evaluations, converged)
+    }
+
+    pub fn integrate_gauss_kronrod(&self, name: &str, lower: f64, upper: f64, tolerance: f64) -> Result<Quadrature, QuadratureError> {
+        validate(lower, upper, tolerance)?;
+
+        let (a, b) = (lower.min(upper), lower.max(upper));
+
+        if a == b {
+            return finish(0.0, 0.0, 0, true);
+        }
+
+        let mut integrand = Integrand::new(self, name);
+        let (value, error) = integrand.kronrod(a, b)?;
+        let mut intervals = vec![(a, b, value, error)];
+
+        loop {
+            let value: f64 = intervals.iter().map(|interval| interval.2).sum();
+            let error: f64 = intervals.iter().map(|interval| interval.3).sum();
+
+            if error <= tolerance {
+                return finish(oriented(lower, upper, value), error, integrand.evaluations, true);
+            }
+
+            let worst = (0..intervals.len())
+                .max_by(|&i, &j| intervals[i].3.partial_cmp(&intervals[j].3).unwrap_or(std::cmp::Ordering::Equal))
+                .unwrap_or(0);
+
+            let (a, b, _, _) = intervals[worst];
+            let m = (a + b) / 2.0;
+
+            if intervals.len() >= MAX_INTERVALS || m <= a || m >= b {
+                return finish(oriented(lower, upper, value), error, integrand.evaluations, false);
+            }
+
+            let (lhs, lhs_error) = integrand.kronrod(a, m)?;
+            let (rhs, rhs_error) = integrand.kronrod(m, b)?;
+
+            intervals[worst] = (a, m, lhs, lhs_error);
+            intervals.push((m, b, rhs, rhs_error));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QuadratureErrorKind;
+    use crate::precise::expression::{ Expr, EvalErrorKind };
+
+    fn parse(source: &str) -> Expr {
+        source.parse().unwrap()
+    }
+
+    #[test]
+    fn simpson() {
+        let result = parse("sin(x)").integrate_simpson("x", 0.0, std::f64::consts::PI, 1e-10).unwrap();
+
+        assert!((result.value() - 2.0).abs() < 1e-9);
+        assert!(result.error() <= 1e-10);
+        assert!(result.evaluations() > 0);
+
+        let result = parse("x ^ 3 - 2x").integrate_simpson("x", 2.0, -1.0, 1e-10).unwrap();
+
+        assert!((result.value() + 0.75).abs() < 1e-12);
+    }
+
+    #[test]
+    fn gauss_kronrod() {
+        let result = parse("exp(-x ^ 2)").integrate_gauss_kronrod("x", -5.0, 5.0, 1e-12).unwrap();
+
+        assert!((result.value() - std::f64::consts::PI.sqrt()).abs() < 1e-10);
+        assert!(result.error() <= 1e-12);
+
+        let result = parse("x ^ 2").integrate_gauss_kronrod("x", 3.0, 0.0, 1e-12).unwrap();
+
+        assert!((result.value() + 9.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn endpoint_singularities() {
+        let expression = parse("1 / sqrt(x)");
+
+        let result = expression.integrate_gauss_kronrod("x", 0.0, 1.0, 1e-8).unwrap();
+        assert!((result.value() - 2.0).abs() < 1e-7);
+
+        let result = expression.integrate_simpson("x", 0.0, 1.0, 1e-6).unwrap();
+        assert!((result.value() - 2.0).abs() < 1e-5);
+
+        let expression = parse("ln(x)");
+
+        let result = expression.integrate_gauss_kronrod("x", 0.0, 1.0, 1e-8).unwrap();
+        assert!((result.value() + 1.0).abs() < 1e-7);
+
+        let result = expression.integrate_simpson("x", 0.0, 1.0, 1e-8).unwrap();
+        assert!((result.value() + 1.0).abs() < 1e-7);
+    }
+
+    #[test]
+    fn errors() {
+        let result = parse("1 / x").integrate_simpson("x", -1.0, 1.0, 1e-8);
+        assert!(matches!(result.unwrap_err().kind(), QuadratureErrorKind::Evaluation(error) if *error.kind() == EvalErrorKind::DivisionByZero));
+
+        let result = parse("x * y").integrate_gauss_kronrod("x", 0.0, 1.0, 1e-8);
+        assert!(matches!(result.unwrap_err().kind(), QuadratureErrorKind::Evaluation(error) if *error.kind() == EvalErrorKind::UnboundVariable(String::from("y"))));
+
+        let result = parse("x").integrate_simpson("x", 0.0, f64::INFINITY, 1e-8);
+        assert_eq!(result.unwrap_err().kind(), &QuadratureErrorKind::InvalidBounds);
+
+        let result = parse("x").integrate_gauss_kronrod("x", 0.0, 1.0, 0.0);
+        assert_eq!(result.unwrap_err().kind(), &QuadratureErrorKind::InvalidTolerance);
+    }
+
+    #[test]
+    fn not_converged() {
+        let result = parse("sin(1 / x)").integrate_gauss_kronrod("x", 0.0, 1.0, 1e-15);
+        let error = result.unwrap_err();
+
+        assert_eq!(error.kind(), &QuadratureErrorKind::NotConverged);
+        assert!((error.estimate().unwrap().value() - 0.504_067_061_906_928_4).abs() < 1e-3);
+
+        let result = parse("sin(1 / x)").integrate_simpson("x", 1e-9, 1.0, 1e-12);
+        let error = result.unwrap_err();
+        let estimate = error.estimate().unwrap();
+
+        assert_eq!(error.kind(), &QuadratureErrorKind::NotConverged);
+        assert!(estimate.evaluations() <= 100_200);
+        assert!(estimate.error() > 1e-12);
+        assert!((estimate.value() - 0.504_067_061_906_928_4).abs() < 1e-2);
+    }
+}
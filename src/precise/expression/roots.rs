@@ -0,0 +1,365 @@
+use std::fmt;
+use crate::precise::expression::{ Expr, EvalError };
+use crate::precise::expression::environment::Environment;
+
+const MAX_ITERATIONS: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Root {
+    value: f64,
+    residual: f64,
+    iterations: usize,
+}
+
+impl Root {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn residual(&self) -> f64 {
+        self.residual
+    }
+
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RootErrorKind {
+    InvalidBracket,
+    InvalidTolerance,
+    Evaluation(EvalError),
+    ZeroDerivative,
+    NotConverged,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RootError {
+    kind: RootErrorKind,
+    estimate: Option<Root>,
+}
+
+impl RootError {
+    fn new(kind: RootErrorKind) -> RootError {
+        RootError {
+            kind,
+            estimate: None,
+        }
+    }
+
+    fn with_estimate(kind: RootErrorKind, value: f64, residual: f64, iterations: usize) -> RootError {
+        RootError {
+            kind,
+            estimate: Some(Root {
+                value,
+                residual,
+                iterations,
+            }),
+        }
+    }
+
+    pub fn kind(&self) -> &RootErrorKind {
+        &self.kind
+    }
+
+    pub fn estimate(&self) -> Option<&Root> {
+        self.estimate.as_ref()
+    }
+}
+
+impl From<EvalError> for RootError {
+    fn from(error: EvalError) -> Self {
+        RootError::new(RootErrorKind::Evaluation(error))
+    }
+}
+
+impl fmt::Display for RootErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RootErrorKind::InvalidBracket => write!(f, "bracket must be finite and change sign"),
+            RootErrorKind::InvalidTolerance => write!(f, "tolerance must be positive"),
+            RootErrorKind::Evaluation(error) => write!(f, "{}", error),
+            RootErrorKind::ZeroDerivative => write!(f, "derivative vanished"),
+            RootErrorKind::NotConverged => write!(f, "root finding did not reach the requested tolerance"),
+        }
+    }
+}
+
+impl fmt::Display for RootError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.estimate {
+            Some(estimate) => write!(f, "{} (last estimate {} after {} iterations)", self.kind, estimate.value, estimate.iterations),
+            None => write!(f, "{}", self.kind),
+        }
+    }
+}
+
+impl std::error::Error for RootError {}
+
+struct Objective<'a> {
+    expression: &'a Expr,
+    name: &'a str,
+    environment: Environment,
+}
+
+impl<'a> Objective<'a> {
+    fn new(expression: &'a Expr, name: &'a str) -> Objective<'a> {
+        Objective {
+            expression,
+            name,
+            environment: Environment::new(),
+        }
+    }
+
+    fn eval(&mut self, x: f64) -> Result<f64, EvalError> {
+        self.environment.bind(self.name, x);
+        self.expression.eval_with(&self.environment)
+    }
+}
+
+fn root(value: f64, residual: f64, iterations: usize) -> Result<Root, RootError> {
+    Ok(Root {
+        value,
+        residual: residual.abs(),
+        iterations,
+    })
+}
+
+fn validate_tolerance(tolerance: f64) -> Result<(), RootError> {
+    if tolerance.is_nan() || tolerance <= 0.0 {
+        Err(RootError::new(RootErrorKind::InvalidTolerance))
+    } else {
+        Ok(())
+    }
+}
+
+fn bracket(objective: &mut Objective, lower: f64, upper: f64, tolerance: f64) -> Result<(f64, f64, f64, f64), RootError> {
+    validate_tolerance(tolerance)?;
+
+    if !lower.is_finite() || !upper.is_finite() {
+        return Err(RootError::new(RootErrorKind::InvalidBracket));
+    }
+
+    let fa = objective.eval(lower)?;
+    let fb = objective.eval(upper)?;
+
+    if fa != 0.0 && fb != 0.0 && fa.signum() == fb.signum() {
+        return Err(RootError::new(RootErrorKind::InvalidBracket));
+    }
+
+    Ok((lower, fa, upper, fb))
+}
+
+impl Expr {
+    pub fn bisection(&self, name: &str, lower: f64, upper: f64, tolerance: f64) -> Result<Root, RootError> {
+        let mut objective = Objective::new(self, name);
+        let (mut a, mut fa, mut b, fb) = bracket(&mut objective, lower, upper, tolerance)?;
+
+        if fa == 0.0 {
+            return root(a, fa, 0);
+        } else if fb == 0.0 {
+            return root(b, fb, 0);
+        }
+
+        for iteration in 1..=MAX_ITERATIONS {
+            let m = a + (b - a) / 2.0;
+            let fm = objective.eval(m)?;
+
+            if fm == 0.0 || (b - a).abs() / 2.0 <= tolerance || m == a || m == b {
+                return root(m, fm, iteration);
+            }
+
+            if fm.signum() == fa.signum() {
+                a = m;
+                fa = fm;
+            } else {
+                b = m;
+            }
+        }
+
+        let m = a + (b - a) / 2.0;
+
+        Err(RootError::with_estimate(RootErrorKind::NotConverged, m, objective.eval(m)?.abs(), MAX_ITERATIONS))
+    }
+
+    pub fn brent(&self, name: &str, lower: f64, upper: f64, tolerance: f64) -> Result<Root, RootError> {
+        let mut objective = Objective::new(self, name);
+        let (mut a, mut fa, mut b, mut fb) = bracket(&mut objective, lower, upper, tolerance)?;
+        let (mut c, mut fc) = (b, fb);
+        let (mut d, mut e) = (b - a, b - a);
+
+        for iteration in 1..=MAX_ITERATIONS {
+            if fb.signum() == fc.signum() && fb != 0.0 {
+                c = a;
+                fc = fa;
+                d = b - a;
+                e = d;
+            }
+
+            if fc.abs() < fb.abs() {
+                a = b;
+                b = c;
+                c = a;
+                fa = fb;
+                fb = fc;
+                fc = fa;
+            }
+
+            let margin = 2.0 * f64::EPSILON * b.abs() + tolerance / 2.0;
+            let midpoint = (c - b) / 2.0;
+
+            if midpoint.abs() <= margin || fb == 0.0 {
+                return root(b, fb, iteration);
+            }
+
+            if e.abs() >= margin && fa.abs() > fb.abs() {
+                let s = fb / fa;
+
+                let (mut p, mut q) = if a == c {
+                    (2.0 * midpoint * s, 1.0 - s)
+                } else {
+                    let q = fa / fc;
+                    let r = fb / fc;
+
+                    (
+                        s * (2.0 * midpoint * q * (q - r) - (b - a) * (r - 1.0)),
+                        (q - 1.0) * (r - 1.0) * (s - 1.0),
+                    )
+                };
+
+                if p > 0.0 {
+                    q = -q;
+                } else {
+                    p = -p;
+                }
+
+                if 2.0 * p < (3.0 * midpoint * q - (margin * q).abs()).min((e * q).abs()) {
+                    e = d;
+                    d = p / q;
+                } else {
+                    d = midpoint;
+                    e = d;
+                }
+            } else {
+                d = midpoint;
+                e = d;
+            }
+
+            a = b;
+            fa = fb;
+
+            b += if d.abs() > margin { d } else { margin.copysign(midpoint) };
+            fb = objective.eval(b)?;
+        }
+
+        Err(RootError::with_estimate(RootErrorKind::NotConverged, b, fb.abs(), MAX_ITERATIONS))
+    }
+
+    pub fn newton(&self, name: &str, initial: f64, tolerance: f64) -> Result<Root, RootError> {
+        validate_tolerance(tolerance)?;
+
+        let derivative = self.derivative(name);
+
+        let mut objective = Objective::new(self, name);
+        let mut slope = Objective::new(&derivative, name);
+
+        let mut x = initial;
+        let mut fx = objective.eval(x)?;
+
+        for iteration in 1..=MAX_ITERATIONS {
+            if fx == 0.0 {
+                return root(x, fx, iteration - 1);
+            }
+
+            let dx = slope.eval(x)?;
+
+            if dx == 0.0 {
+                return Err(RootError::with_estimate(RootErrorKind::ZeroDerivative, x, fx.abs(), iteration - 1));
+            }
+
+            let step = fx / dx;
+            x -= step;
+            fx = objective.eval(x)?;
+
+            if step.abs() <= tolerance {
+                return root(x, fx, iteration);
+            }
+        }
+
+        Err(RootError::with_estimate(RootErrorKind::NotConverged, x, fx.abs(), MAX_ITERATIONS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RootErrorKind;
+    use crate::precise::expression::{ Expr, EvalErrorKind };
+
+    fn parse(source: &str) -> Expr {
+        source.parse().unwrap()
+    }
+
+    #[test]
+    fn bisection() {
+        let result = parse("x ^ 2 - 2").bisection("x", 0.0, 2.0, 1e-12).unwrap();
+
+        assert!((result.value() - std::f64::consts::SQRT_2).abs() < 1e-12);
+        assert!(result.residual() < 1e-11);
+        assert!(result.iterations() > 30);
+
+        let result = parse("x - 1").bisection("x", 1.0, 3.0, 1e-12).unwrap();
+
+        assert_eq!(result.value(), 1.0);
+        assert_eq!(result.iterations(), 0);
+    }
+
+    #[test]
+    fn brent() {
+        let result = parse("cos(x) - x").brent("x", 0.0, 1.0, 1e-14).unwrap();
+
+        assert!((result.value() - 0.739_085_133_215_160_6).abs() < 1e-13);
+
+        let bisection = parse("x ^ 3 - 2x - 5").bisection("x", 2.0, 3.0, 1e-12).unwrap();
+        let brent = parse("x ^ 3 - 2x - 5").brent("x", 2.0, 3.0, 1e-12).unwrap();
+
+        assert!((brent.value() - bisection.value()).abs() < 1e-11);
+        assert!(brent.iterations() < bisection.iterations());
+    }
+
+    #[test]
+    fn newton() {
+        let result = parse("x ^ 2 - 2").newton("x", 1.0, 1e-14).unwrap();
+
+        assert!((result.value() - std::f64::consts::SQRT_2).abs() < 1e-15);
+        assert!(result.iterations() < 10);
+
+        let result = parse("exp(x) - 3").newton("x", 0.0, 1e-12).unwrap();
+
+        assert!((result.value() - 3f64.ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn errors() {
+        let result = parse("x ^ 2 + 1").bisection("x", -1.0, 1.0, 1e-8);
+        assert_eq!(result.unwrap_err().kind(), &RootErrorKind::InvalidBracket);
+
+        let result = parse("x").brent("x", 0.0, 1.0, -1.0);
+        assert_eq!(result.unwrap_err().kind(), &RootErrorKind::InvalidTolerance);
+
+        let error = parse("x ^ 2 + 1").newton("x", 0.0, 1e-8).unwrap_err();
+        assert_eq!(error.kind(), &RootErrorKind::ZeroDerivative);
+        assert_eq!(error.estimate().unwrap().value(), 0.0);
+
+        let result = parse("ln(x) - 1").newton("x", 10.0, 1e-8);
+        assert!(matches!(result.unwrap_err().kind(), RootErrorKind::Evaluation(error) if *error.kind() == EvalErrorKind::DomainError));
+    }
+
+    #[test]
+    fn not_converged() {
+        let error = parse("x ^ 3 - 2x + 2").newton("x", 0.0, 1e-12).unwrap_err();
+
+        assert_eq!(error.kind(), &RootErrorKind::NotConverged);
+        assert_eq!(error.estimate().unwrap().iterations(), 1000);
+    }
+}
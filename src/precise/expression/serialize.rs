@@ -0,0 +1,122 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+use serde::{ Serialize, Serializer, Deserialize, Deserializer };
+use serde::de::Error;
+use serde_derive::{ Serialize as DeriveSerialize, Deserialize as DeriveDeserialize };
+use crate::precise::expression::Expr;
+use crate::precise::expression::constant::Constant;
+use crate::precise::expression::function::Function;
+use crate::precise::integer::Integer;
+
+#[derive(DeriveSerialize, DeriveDeserialize)]
+enum Token<'a> {
+    Val(Cow<'a, Integer>),
+    Var(Cow<'a, str>),
+    Const(Constant),
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Exp,
+    Fun(Function),
+}
+
+impl Serialize for Expr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tokens = Vec::new();
+
+        self.postorder::<(), _>(|expression| {
+            tokens.push(match expression {
+                Expr::Val(x) => Token::Val(Cow::Borrowed(x)),
+                Expr::Var(x) => Token::Var(Cow::Borrowed(x)),
+                Expr::Const(x) => Token::Const(*x),
+                Expr::Add(_, _) => Token::Add,
+                Expr::Sub(_, _) => Token::Sub,
+                Expr::Mul(_, _) => Token::Mul,
+                Expr::Div(_, _) => Token::Div,
+                Expr::Rem(_, _) => Token::Rem,
+                Expr::Exp(_, _) => Token::Exp,
+                Expr::Fun(function, _) => Token::Fun(*function),
+            });
+
+            Ok(())
+        }).unwrap();
+
+        serializer.collect_seq(tokens)
+    }
+}
+
+impl<'de> Deserialize<'de> for Expr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Expr, D::Error> {
+        let tokens: Vec<Token> = Vec::deserialize(deserializer)?;
+        let mut stack: Vec<Expr> = Vec::new();
+
+        for token in tokens {
+            let operator: fn(Arc<Expr>, Arc<Expr>) -> Expr = match token {
+                Token::Val(x) => {
+                    stack.push(Expr::Val(x.into_owned()));
+                    continue;
+                },
+                Token::Var(x) => {
+                    stack.push(Expr::Var(x.into_owned()));
+                    continue;
+                },
+                Token::Const(x) => {
+                    stack.push(Expr::Const(x));
+                    continue;
+                },
+                Token::Fun(function) => {
+                    let arity = function.arity();
+
+                    if stack.len() < arity {
+                        return Err(D::Error::custom(format!("missing arguments to `{}`", function)));
+                    }
+
+                    let arguments = stack.split_off(stack.len() - arity);
+                    stack.push(Expr::Fun(function, arguments.into()));
+                    continue;
+                },
+                Token::Add => Expr::Add,
+                Token::Sub => Expr::Sub,
+                Token::Mul => Expr::Mul,
+                Token::Div => Expr::Div,
+                Token::Rem => Expr::Rem,
+                Token::Exp => Expr::Exp,
+            };
+
+            match (stack.pop(), stack.pop()) {
+                (Some(rhs), Some(lhs)) => stack.push(operator(Arc::new(lhs), Arc::new(rhs))),
+                _ => return Err(D::Error::custom("missing operand")),
+            }
+        }
+
+        match (stack.pop(), stack.is_empty()) {
+            (Some(expression), true) => Ok(expression),
+            (None, _) => Err(D::Error::custom("empty expression")),
+            (Some(_), false) => Err(D::Error::custom("unused operands")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::precise::expression::Expr;
+
+    #[test]
+    fn format() {
+        let expression: Expr = "2x - sin(y)".parse().unwrap();
+        let json = serde_json::to_string(&expression).unwrap();
+
+        assert_eq!(json, r#"[{"Val":2},{"Var":"x"},"Mul",{"Var":"y"},{"Fun":"sin"},"Sub"]"#);
+        assert!(serde_json::from_str::<Expr>(&json).unwrap().structural_eq(&expression));
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(serde_json::from_str::<Expr>(r#"[{"Var":"x"},"Add"]"#).is_err());
+        assert!(serde_json::from_str::<Expr>(r#"[{"Var":"x"},{"Var":"y"}]"#).is_err());
+        assert!(serde_json::from_str::<Expr>(r#"[{"Fun":"max"}]"#).is_err());
+        assert!(serde_json::from_str::<Expr>("[]").is_err());
+    }
+}
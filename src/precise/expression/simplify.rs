@@ -0,0 +1,1101 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use crate::precise::expression::Expr;
+use crate::precise::expression::constant::Constant;
+use crate::precise::expression::function::Function;
+use crate::precise::expression::structural::Structural;
+use crate::precise::integer::Integer;
+use crate::precise::rational::Rational;
+use crate::precise::trace::{ Rule, Trace };
+
+const EXPANSION_LIMIT: usize = 512;
+const EXPANSION_PASSES: usize = 8;
+const ROOT_EXTRACTION_BITS: u64 = 4096;
+const TRIAL_DIVISION_LIMIT: u32 = 10_000;
+
+pub(crate) fn as_number(expression: &Expr) -> Option<Rational> {
+    match expression {
+        Expr::Val(x) => Some(Rational::from(x.clone())),
+        Expr::Div(x, y) => match (&**x, &**y) {
+            (Expr::Val(x), Expr::Val(y)) if !y.is_zero() => Some(Rational::new(x.clone(), y.clone())),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+type Factors = BTreeMap<Structural, Structural>;
+
+#[derive(Debug, Clone)]
+struct Term {
+    coefficient: Rational,
+    factors: Factors,
+}
+
+impl Term {
+    fn constant(value: Rational) -> Term {
+        Term {
+            coefficient: value,
+            factors: Factors::new(),
+        }
+    }
+
+    fn power(base: Expr, exponent: Expr) -> Term {
+        let mut factors = Factors::new();
+        factors.insert(Structural(base), Structural(exponent));
+
+        Term {
+            coefficient: Rational::one(),
+            factors,
+        }
+    }
+
+    fn atom(base: Expr) -> Term {
+        Term::power(base, Expr::from(1))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Sum {
+    terms: BTreeMap<Factors, Rational>,
+}
+
+impl Sum {
+    fn constant(value: Rational) -> Sum {
+        Sum::from(Term::constant(value))
+    }
+
+    fn add_term(&mut self, term: Term) {
+        if term.coefficient.is_zero() {
+            return;
+        }
+
+        let coefficient = match self.terms.remove(&term.factors) {
+            Some(existing) => existing + term.coefficient,
+            None => term.coefficient,
+        };
+
+        if !coefficient.is_zero() {
+            self.terms.insert(term.factors, coefficient);
+        }
+    }
+
+    fn add(&mut self, rhs: Sum) {
+        for (factors, coefficient) in rhs.terms {
+            self.add_term(Term {
+                coefficient,
+                factors,
+            });
+        }
+    }
+
+    fn scale(mut self, value: &Rational) -> Sum {
+        if value.is_zero() {
+            return Sum::default();
+        }
+
+        for coefficient in self.terms.values_mut() {
+            *coefficient = &*coefficient * value;
+        }
+
+        self
+    }
+
+    fn as_constant(&self) -> Option<Rational> {
+        match self.terms.len() {
+            0 => Some(Rational::zero()),
+            1 => self.terms.iter()
+                .next()
+                .filter(|(factors, _)| factors.is_empty())
+                .map(|(_, coefficient)| coefficient.clone()),
+            _ => None,
+        }
+    }
+
+    fn single_term(&self) -> Option<Term> {
+        if self.terms.len() != 1 {
+            return None;
+        }
+
+        self.terms.iter().next().map(|(factors, coefficient)| Term {
+            coefficient: coefficient.clone(),
+            factors: factors.clone(),
+        })
+    }
+}
+
+impl From<Term> for Sum {
+    fn from(term: Term) -> Self {
+        let mut sum = Sum::default();
+        sum.add_term(term);
+        sum
+    }
+}
+
+struct Simplifier;
+
+impl Simplifier {
+    fn simplify(&self, expression: &Expr) -> Expr {
+        let sum = self.collect(expression);
+        let sum = self.expand(&sum);
+
+        self.rebuild(&sum)
+    }
+
+    fn collect(&self, expression: &Expr) -> Sum {
+        match expression {
+            Expr::Val(x) => Sum::constant(Rational::from(x.clone())),
+            Expr::Var(_) | Expr::Const(_) => Sum::from(Term::atom(expression.clone())),
+            Expr::Add(x, y) => {
+                let mut sum = self.collect(x);
+                sum.add(self.collect(y));
+                sum
+            },
+            Expr::Sub(x, y) => {
+                let mut sum = self.collect(x);
+                sum.add(self.collect(y).scale(&Rational::from(-1)));
+                sum
+            },
+            Expr::Mul(x, y) => self.multiply(&self.collect(x), &self.collect(y)),
+            Expr::Div(x, y) => {
+                let denominator = self.power(&self.collect(y), &Sum::constant(Rational::from(-1)));
+
+                self.multiply(&self.collect(x), &denominator)
+            },
+            Expr::Rem(x, y) => {
+                let lhs = self.simplify(x);
+                let rhs = self.simplify(y);
+
+                match (as_number(&lhs), as_number(&rhs)) {
+                    (Some(a), Some(b)) if !b.is_zero() => Sum::constant(a % b),
+                    _ => Sum::from(Term::atom(Expr::Rem(Arc::new(lhs), Arc::new(rhs)))),
+                }
+            },
+            Expr::Exp(x, y) => self.power(&self.collect(x), &self.collect(y)),
+            Expr::Fun(Function::Sqrt, arguments) => self.power(&self.collect(&arguments[0]), &Sum::constant(Rational::new(1, 2))),
+            Expr::Fun(Function::Exp, arguments) => self.power(&Sum::from(Term::atom(Expr::Const(Constant::E))), &self.collect(&arguments[0])),
+            Expr::Fun(function, arguments) => {
+                let arguments: Vec<Expr> = arguments.iter().map(|argument| self.simplify(argument)).collect();
+
+                let value = arguments.iter()
+                    .map(as_number)
+                    .collect::<Option<Vec<Rational>>>()
+                    .and_then(|values| function.eval_exact(&values).ok());
+
+                if let Some(value) = value.or_else(|| self.trigonometric(*function, &arguments[0])) {
+                    return Sum::constant(value);
+                }
+
+                match (function, &arguments[0]) {
+                    (Function::Ln, Expr::Const(Constant::E)) => Sum::constant(Rational::one()),
+                    (Function::Ln, Expr::Exp(base, exponent)) if base.structural_eq(&Expr::e()) => self.collect(exponent),
+                    (Function::Abs, Expr::Fun(Function::Abs, _)) => self.collect(&arguments[0]),
+                    _ => Sum::from(Term::atom(Expr::Fun(*function, arguments.into()))),
+                }
+            },
+        }
+    }
+
+    fn trigonometric(&self, function: Function, argument: &Expr) -> Option<Rational> {
+        let term = self.collect(argument).single_term()?;
+        let (base, power) = term.factors.iter().next()?;
+
+        if term.factors.len() != 1 || !base.0.structural_eq(&Expr::pi()) || !as_number(&power.0)?.is_one() {
+            return None;
+        }
+
+        match function {
+            Function::Sin => sine_of_pi_multiple(&term.coefficient),
+            Function::Cos => sine_of_pi_multiple(&(term.coefficient + Rational::new(1, 2))),
+            Function::Tan => tangent_of_pi_multiple(&term.coefficient),
+            _ => None,
+        }
+    }
+
+    fn as_term(&self, sum: &Sum) -> Option<Term> {
+        if sum.terms.is_empty() {
+            return None;
+        }
+
+        if let Some(term) = sum.single_term() {
+            return Some(term);
+        }
+
+        let expanded = self.expand(sum);
+
+        if expanded.terms.len() <= 1 {
+            return self.as_term(&expanded);
+        }
+
+        let (content, primitive) = self.content(&expanded);
+        let mut term = Term::atom(self.rebuild(&primitive));
+        term.coefficient = content;
+
+        Some(term)
+    }
+
+    fn multiply(&self, lhs: &Sum, rhs: &Sum) -> Sum {
+        match (self.as_term(lhs), self.as_term(rhs)) {
+            (Some(a), Some(b)) => Sum::from(self.multiply_terms(a, b)),
+            _ => Sum::default(),
+        }
+    }
+
+    fn multiply_terms(&self, lhs: Term, rhs: Term) -> Term {
+        let mut result = lhs;
+        result.coefficient = &result.coefficient * &rhs.coefficient;
+
+        for (base, exponent) in rhs.factors {
+            self.insert_factor(&mut result, base.0, exponent.0);
+        }
+
+        result
+    }
+
+    fn insert_factor(&self, term: &mut Term, base: Expr, exponent: Expr) {
+        match term.factors.remove(&Structural(base.clone())) {
+            Some(existing) if !combinable(&base, &existing.0, &exponent) => {
+                term.factors.insert(Structural(base.clone()), existing);
+                self.insert_factor(term, Expr::Exp(Arc::new(base), Arc::new(exponent)), Expr::from(1));
+            },
+            Some(existing) => {
+                let exponent = self.add_expressions(&existing.0, &exponent);
+                let power = self.power_factor(base, exponent);
+
+                term.coefficient = &term.coefficient * &power.coefficient;
+
+                for (base, exponent) in power.factors {
+                    self.insert_factor(term, base.0, exponent.0);
+                }
+            },
+            None => {
+                term.factors.insert(Structural(base), Structural(exponent));
+            },
+        }
+    }
+
+    fn add_expressions(&self, lhs: &Expr, rhs: &Expr) -> Expr {
+        let mut sum = self.collect(lhs);
+        sum.add(self.collect(rhs));
+
+        self.rebuild(&self.expand(&sum))
+    }
+
+    fn multiply_expressions(&self, lhs: &Expr, rhs: &Expr) -> Expr {
+        let product = self.multiply(&self.collect(lhs), &self.collect(rhs));
+
+        self.rebuild(&self.expand(&product))
+    }
+
+    fn power(&self, base: &Sum, exponent: &Sum) -> Sum {
+        let exponent_value = match exponent.as_constant() {
+            Some(value) => value,
+            None => {
+                if let Some(value) = base.as_constant() {
+                    if value.is_one() {
+                        return Sum::constant(value);
+                    }
+                }
+
+                let base = self.rebuild(&self.expand(base));
+                let exponent = self.rebuild(&self.expand(exponent));
+
+                return Sum::from(self.power_atom(base, exponent));
+            },
+        };
+
+        if exponent_value.is_zero() {
+            return Sum::constant(Rational::one());
+        }
+
+        if let Some(value) = base.as_constant() {
+            return Sum::from(self.numeric_power(&value, &exponent_value));
+        }
+
+        if let Some(term) = base.single_term() {
+            if exponent_value.is_integer() {
+                return Sum::from(self.integer_power(term, &exponent_value));
+            }
+
+            if term.coefficient.is_one() && term.factors.len() == 1 {
+                let (factor, power) = term.factors.iter().next().unwrap();
+
+                if as_number(&power.0).is_some_and(|power| power.is_one()) {
+                    return Sum::from(self.power_atom(factor.0.clone(), Expr::from(exponent_value)));
+                }
+            }
+
+            return Sum::from(Term::power(self.rebuild(base), Expr::from(exponent_value)));
+        }
+
+        let expanded = self.expand(base);
+
+        if expanded.terms.len() <= 1 {
+            return self.power(&expanded, exponent);
+        }
+
+        let (content, primitive) = self.content(&expanded);
+
+        if exponent_value.is_integer() || !content.is_negative() {
+            let mut term = Term::power(self.rebuild(&primitive), Expr::from(exponent_value.clone()));
+            let scale = self.numeric_power(&content, &exponent_value);
+
+            term = self.multiply_terms(term, scale);
+
+            Sum::from(term)
+        } else {
+            Sum::from(Term::power(self.rebuild(&expanded), Expr::from(exponent_value)))
+        }
+    }
+
+    fn integer_power(&self, term: Term, exponent: &Rational) -> Term {
+        let coefficient = self.numeric_power(&term.coefficient, exponent);
+        let mut result = coefficient;
+
+        for (base, power) in term.factors {
+            if !nestable(&base.0, &power.0, exponent) {
+                let factor = Term::power(Expr::Exp(Arc::new(base.0), Arc::new(power.0)), Expr::from(exponent.clone()));
+
+                result = self.multiply_terms(result, factor);
+                continue;
+            }
+
+            let power = self.multiply_expressions(&power.0, &Expr::from(exponent.clone()));
+            let factor = self.power_factor(base.0, power);
+
+            result = self.multiply_terms(result, factor);
+        }
+
+        result
+    }
+
+    fn power_factor(&self, base: Expr, exponent: Expr) -> Term {
+        match as_number(&exponent) {
+            Some(value) if value.is_integer() && is_compound(&base) => {
+                let power = self.power(&self.collect(&base), &Sum::constant(value));
+
+                self.as_term(&power).unwrap_or_else(|| Term::constant(Rational::zero()))
+            },
+            _ => self.power_atom(base, exponent),
+        }
+    }
+
+    fn power_atom(&self, base: Expr, exponent: Expr) -> Term {
+        match as_number(&exponent) {
+            Some(value) => {
+                if value.is_zero() {
+                    return Term::constant(Rational::one());
+                }
+
+                match as_number(&base) {
+                    Some(base) => self.numeric_power(&base, &value),
+                    None if value.is_integer() && base.structural_eq(&Expr::i()) => imaginary_power(value.numerator()),
+                    None => Term::power(base, exponent),
+                }
+            },
+            None => {
+                if as_number(&base).is_some_and(|base| base.is_one()) {
+                    Term::constant(Rational::one())
+                } else {
+                    Term::power(base, exponent)
+                }
+            },
+        }
+    }
+
+    fn numeric_power(&self, base: &Rational, exponent: &Rational) -> Term {
+        if exponent.is_zero() {
+            return Term::constant(Rational::one());
+        }
+
+        if base.is_zero() {
+            return if exponent.is_negative() {
+                Term::power(Expr::from(0), Expr::from(exponent.clone()))
+            } else {
+                Term::constant(Rational::zero())
+            };
+        }
+
+        if base.is_negative() && !exponent.is_integer() {
+            return Term::power(Expr::from(base.clone()), Expr::from(exponent.clone()));
+        }
+
+        if exponent.is_integer() {
+            return match base.checked_pow(exponent.numerator()) {
+                Some(value) => Term::constant(value),
+                None => Term::power(Expr::from(base.clone()), Expr::from(exponent.clone())),
+            };
+        }
+
+        if let Some(root) = base.root(exponent.denominator()) {
+            if let Some(value) = root.checked_pow(exponent.numerator()) {
+                return Term::constant(value);
+            }
+        }
+
+        let fallback = Term::power(Expr::from(base.clone()), Expr::from(exponent.clone()));
+
+        let whole = exponent.floor();
+        let fraction = exponent - &whole;
+
+        let degree = match fraction.denominator().to_u32() {
+            Some(degree) => degree,
+            None => return fallback,
+        };
+        let power = fraction.numerator().to_u32().unwrap_or(0);
+
+        let bits = base.numerator().bits() * u64::from(power) + base.denominator().bits() * u64::from(degree - power);
+
+        if bits > ROOT_EXTRACTION_BITS {
+            return fallback;
+        }
+
+        let coefficient = match base.checked_pow(whole.numerator()) {
+            Some(value) => value,
+            None => return fallback,
+        };
+
+        let radicand = base.numerator().pow(power) * base.denominator().pow(degree - power);
+        let (outside, inside) = extract_root(&radicand, degree);
+
+        let mut term = Term::constant(coefficient * Rational::new(outside, base.denominator().clone()));
+
+        if !inside.is_one() {
+            term.factors.insert(Structural(Expr::from(inside)), Structural(Expr::from(Rational::new(1, degree))));
+        }
+
+        term
+    }
+
+    fn content(&self, sum: &Sum) -> (Rational, Sum) {
+        let mut numerator = Integer::zero();
+        let mut denominator = Integer::one();
+
+        for coefficient in sum.terms.values() {
+            numerator = numerator.gcd(coefficient.numerator());
+            denominator = &(&denominator * coefficient.denominator()) / &denominator.gcd(coefficient.denominator());
+        }
+
+        let mut content = Rational::new(numerator, denominator);
+
+        if let Some((_, leading)) = self.ordered_terms(sum).first() {
+            if leading.is_negative() {
+                content = -content;
+            }
+        }
+
+        let primitive = sum.clone().scale(&content.recip());
+
+        (content, primitive)
+    }
+
+    fn expand(&self, sum: &Sum) -> Sum {
+        let mut result = self.expand_once(sum);
+
+        for _ in 1..EXPANSION_PASSES {
+            let next = self.expand_once(&result);
+
+            if next.terms == result.terms {
+                break;
+            }
+
+            result = next;
+        }
+
+        result
+    }
+
+    fn expand_once(&self, sum: &Sum) -> Sum {
+        let mut result = Sum::default();
+
+        for (factors, coefficient) in sum.terms.iter() {
+            result.add(self.expand_term(coefficient, factors));
+        }
+
+        result
+    }
+
+    fn expand_term(&self, coefficient: &Rational, factors: &Factors) -> Sum {
+        let mut result = Sum::constant(coefficient.clone());
+        let mut rest = Term::constant(Rational::one());
+
+        for (base, exponent) in factors.iter() {
+            let power = as_number(&exponent.0)
+                .filter(|power| power.is_integer() && !power.is_negative())
+                .and_then(|power| power.numerator().to_u32());
+
+            let expandable = match (&base.0, power) {
+                (Expr::Add(_, _), Some(power)) | (Expr::Sub(_, _), Some(power)) => {
+                    let base_sum = self.collect(&base.0);
+
+                    if self.expansion_size(result.terms.len(), base_sum.terms.len(), power) <= EXPANSION_LIMIT {
+                        Some((base_sum, power))
+                    } else {
+                        None
+                    }
+                },
+                _ => None,
+            };
+
+            match expandable {
+                Some((base_sum, power)) => {
+                    for _ in 0..power {
+                        result = self.distribute(&result, &base_sum);
+                    }
+                },
+                None => {
+                    rest.factors.insert(base.clone(), exponent.clone());
+                },
+            }
+        }
+
+        if rest.factors.is_empty() {
+            result
+        } else {
+            self.distribute(&result, &Sum::from(rest))
+        }
+    }
+
+    fn expansion_size(&self, terms: usize, base_terms: usize, power: u32) -> usize {
+        let mut size = terms;
+
+        for _ in 0..power {
+            size = size.saturating_mul(base_terms);
+
+            if size > EXPANSION_LIMIT {
+                break;
+            }
+        }
+
+        size
+    }
+
+    fn distribute(&self, lhs: &Sum, rhs: &Sum) -> Sum {
+        let mut result = Sum::default();
+
+        for (a_factors, a_coefficient) in lhs.terms.iter() {
+            for (b_factors, b_coefficient) in rhs.terms.iter() {
+                let a = Term {
+                    coefficient: a_coefficient.clone(),
+                    factors: a_factors.clone(),
+                };
+                let b = Term {
+                    coefficient: b_coefficient.clone(),
+                    factors: b_factors.clone(),
+                };
+
+                result.add_term(self.multiply_terms(a, b));
+            }
+        }
+
+        result
+    }
+
+    fn degree(&self, factors: &Factors) -> Option<Rational> {
+        let mut degree = None;
+
+        for (base, exponent) in factors.iter() {
+            if as_number(&base.0).is_some() || matches!(base.0, Expr::Const(_)) {
+                continue;
+            }
+
+            let power = as_number(&exponent.0).unwrap_or_else(Rational::one);
+            degree = Some(degree.map_or(power.clone(), |degree: Rational| degree + power));
+        }
+
+        degree
+    }
+
+    fn ordered_terms<'a>(&self, sum: &'a Sum) -> Vec<(&'a Factors, &'a Rational)> {
+        let mut terms: Vec<(&Factors, &Rational)> = sum.terms.iter().collect();
+
+        terms.sort_by(|(a, _), (b, _)| {
+            match (self.degree(a), self.degree(b)) {
+                (Some(x), Some(y)) => y.cmp(&x).then_with(|| a.cmp(b)),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => a.cmp(b),
+            }
+        });
+
+        terms
+    }
+
+    fn rebuild(&self, sum: &Sum) -> Expr {
+        let mut result: Option<Expr> = None;
+
+        for (factors, coefficient) in self.ordered_terms(sum) {
+            result = Some(match result {
+                None => self.rebuild_term(coefficient, factors),
+                Some(lhs) => {
+                    if coefficient.is_negative() {
+                        Expr::Sub(Arc::new(lhs), Arc::new(self.rebuild_term(&-coefficient, factors)))
+                    } else {
+                        Expr::Add(Arc::new(lhs), Arc::new(self.rebuild_term(coefficient, factors)))
+                    }
+                },
+            });
+        }
+
+        result.unwrap_or_else(|| Expr::from(0))
+    }
+
+    fn rebuild_term(&self, coefficient: &Rational, factors: &Factors) -> Expr {
+        let mut numerator = Vec::new();
+        let mut denominator = Vec::new();
+        let mut zero = None;
+
+        for (base, exponent) in factors.iter() {
+            match as_number(&exponent.0) {
+                Some(power) if power.is_negative() && as_number(&base.0).is_some_and(|base| base.is_zero()) => {
+                    zero = Some(power);
+                },
+                Some(power) if power.is_negative() => {
+                    denominator.push(self.rebuild_factor(&base.0, &-power));
+                },
+                Some(power) => {
+                    numerator.push(self.rebuild_factor(&base.0, &power));
+                },
+                None => {
+                    numerator.push(Expr::Exp(Arc::new(base.0.clone()), Arc::new(exponent.0.clone())));
+                },
+            }
+        }
+
+        let numerator_value = coefficient.numerator();
+        let denominator_value = coefficient.denominator();
+
+        let numerator = if numerator.is_empty() {
+            Expr::from(numerator_value.clone())
+        } else if numerator_value.is_one() {
+            product(numerator)
+        } else {
+            numerator.insert(0, Expr::from(numerator_value.clone()));
+            product(numerator)
+        };
+
+        if !denominator_value.is_one() {
+            denominator.insert(0, Expr::from(denominator_value.clone()));
+        }
+
+        let result = if denominator.is_empty() {
+            numerator
+        } else {
+            Expr::Div(Arc::new(numerator), Arc::new(product(denominator)))
+        };
+
+        match zero {
+            Some(power) if (-&power).is_one() => Expr::Div(Arc::new(result), Arc::new(Expr::from(0))),
+            Some(power) => Expr::Mul(Arc::new(result), Arc::new(Expr::Exp(Arc::new(Expr::from(0)), Arc::new(Expr::from(power))))),
+            None => result,
+        }
+    }
+
+    fn rebuild_factor(&self, base: &Expr, power: &Rational) -> Expr {
+        if power.is_one() {
+            base.clone()
+        } else {
+            Expr::Exp(Arc::new(base.clone()), Arc::new(Expr::from(power.clone())))
+        }
+    }
+}
+
+fn sine_of_pi_multiple(multiple: &Rational) -> Option<Rational> {
+    let turn = Rational::from(2);
+    let sixths = ((multiple % &turn + turn.clone()) % turn) * Rational::from(6);
+
+    if !sixths.is_integer() {
+        return None;
+    }
+
+    match sixths.numerator().to_i128()? {
+        0 | 6 => Some(Rational::zero()),
+        1 | 5 => Some(Rational::new(1, 2)),
+        3 => Some(Rational::one()),
+        7 | 11 => Some(Rational::new(-1, 2)),
+        9 => Some(Rational::from(-1)),
+        _ => None,
+    }
+}
+
+fn tangent_of_pi_multiple(multiple: &Rational) -> Option<Rational> {
+    let quarters = (multiple - &multiple.floor()) * Rational::from(4);
+
+    if !quarters.is_integer() {
+        return None;
+    }
+
+    match quarters.numerator().to_i128()? {
+        0 => Some(Rational::zero()),
+        1 => Some(Rational::one()),
+        3 => Some(Rational::from(-1)),
+        _ => None,
+    }
+}
+
+fn imaginary_power(exponent: &Integer) -> Term {
+    let (_, remainder) = exponent.div_rem(&Integer::from(4));
+    let remainder = remainder.to_i128().map_or(0, |remainder| (remainder + 4) % 4);
+
+    let mut term = Term::constant(if remainder >= 2 { Rational::from(-1) } else { Rational::one() });
+
+    if remainder % 2 == 1 {
+        term.factors.insert(Structural(Expr::i()), Structural(Expr::from(1)));
+    }
+
+    term
+}
+
+fn extract_root(value: &Integer, degree: u32) -> (Integer, Integer) {
+    let mut outside = Integer::one();
+    let mut inside = Integer::one();
+    let mut remaining = value.clone();
+
+    for prime in 2..TRIAL_DIVISION_LIMIT {
+        let prime = Integer::from(prime);
+
+        if prime.pow(degree) > remaining {
+            break;
+        }
+
+        let mut multiplicity = 0;
+
+        loop {
+            let (quotient, remainder) = remaining.div_rem(&prime);
+
+            if !remainder.is_zero() {
+                break;
+            }
+
+            remaining = quotient;
+            multiplicity += 1;
+        }
+
+        outside = outside * prime.pow(multiplicity / degree);
+        inside = inside * prime.pow(multiplicity % degree);
+    }
+
+    (outside, inside * remaining)
+}
+
+fn is_integer(expression: &Expr) -> bool {
+    as_number(expression).is_some_and(|value| value.is_integer())
+}
+
+fn is_non_negative(expression: &Expr) -> bool {
+    if let Some(value) = as_number(expression) {
+        return !value.is_negative();
+    }
+
+    match expression {
+        Expr::Const(Constant::Pi) | Expr::Const(Constant::E) => true,
+        Expr::Fun(Function::Abs, _) | Expr::Fun(Function::Sqrt, _) | Expr::Fun(Function::Exp, _) => true,
+        Expr::Exp(x, y) => is_non_negative(x) || as_number(y).is_some_and(|y| !y.is_integer() || y.numerator().is_even()),
+        Expr::Add(x, y) | Expr::Mul(x, y) | Expr::Div(x, y) => is_non_negative(x) && is_non_negative(y),
+        _ => false,
+    }
+}
+
+fn is_reciprocal_zero(base: &Expr, power: &Expr) -> bool {
+    as_number(base).is_some_and(|base| base.is_zero()) && as_number(power).is_some_and(|power| power.is_negative())
+}
+
+fn combinable(base: &Expr, lhs: &Expr, rhs: &Expr) -> bool {
+    if is_reciprocal_zero(base, lhs) || is_reciprocal_zero(base, rhs) {
+        return false;
+    }
+
+    if is_integer(lhs) || is_integer(rhs) || is_non_negative(base) {
+        return true;
+    }
+
+    match (as_number(lhs), as_number(rhs)) {
+        (Some(lhs), Some(rhs)) => !(lhs + rhs).is_integer(),
+        _ => false,
+    }
+}
+
+fn nestable(base: &Expr, power: &Expr, exponent: &Rational) -> bool {
+    if is_reciprocal_zero(base, power) {
+        return exponent.is_one();
+    }
+
+    if is_integer(power) || is_non_negative(base) {
+        return true;
+    }
+
+    as_number(power).is_some_and(|power| !(power * exponent.clone()).is_integer())
+}
+
+fn product(factors: Vec<Expr>) -> Expr {
+    let mut factors = factors.into_iter();
+    let first = factors.next().unwrap_or_else(|| Expr::from(1));
+
+    factors.fold(first, |lhs, rhs| Expr::Mul(Arc::new(lhs), Arc::new(rhs)))
+}
+
+fn is_compound(expression: &Expr) -> bool {
+    matches!(expression, Expr::Add(_, _) | Expr::Sub(_, _) | Expr::Mul(_, _) | Expr::Div(_, _) | Expr::Exp(_, _))
+}
+
+fn is_sum(expression: &Expr) -> bool {
+    matches!(expression, Expr::Add(_, _) | Expr::Sub(_, _))
+}
+
+fn rule(expression: &Expr) -> Rule {
+    match expression {
+        Expr::Fun(_, _) => Rule::EvaluateFunction,
+        Expr::Add(x, y) | Expr::Sub(x, y) | Expr::Mul(x, y) | Expr::Div(x, y) | Expr::Rem(x, y) | Expr::Exp(x, y)
+            if as_number(x).is_some() && as_number(y).is_some() => Rule::EvaluateArithmetic,
+        Expr::Add(_, _) | Expr::Sub(_, _) => Rule::CombineLikeTerms,
+        Expr::Mul(x, y) if is_sum(x) || is_sum(y) => Rule::ExpandProduct,
+        Expr::Mul(_, _) => Rule::MultiplyFactors,
+        Expr::Div(_, _) => Rule::CancelCommonFactors,
+        Expr::Exp(x, y) if is_sum(x) && as_number(y).is_some_and(|y| y.is_integer() && y.is_positive()) => Rule::ExpandPower,
+        Expr::Exp(_, _) => Rule::SimplifyPower,
+        _ => Rule::EvaluateArithmetic,
+    }
+}
+
+fn traced(expression: &Expr, trace: &mut Trace) -> Expr {
+    let node = match expression {
+        Expr::Val(_) | Expr::Var(_) | Expr::Const(_) => return expression.clone(),
+        Expr::Add(x, y) => Expr::Add(Arc::new(traced(x, trace)), Arc::new(traced(y, trace))),
+        Expr::Sub(x, y) => Expr::Sub(Arc::new(traced(x, trace)), Arc::new(traced(y, trace))),
+        Expr::Mul(x, y) => Expr::Mul(Arc::new(traced(x, trace)), Arc::new(traced(y, trace))),
+        Expr::Div(x, y) => Expr::Div(Arc::new(traced(x, trace)), Arc::new(traced(y, trace))),
+        Expr::Rem(x, y) => Expr::Rem(Arc::new(traced(x, trace)), Arc::new(traced(y, trace))),
+        Expr::Exp(x, y) => Expr::Exp(Arc::new(traced(x, trace)), Arc::new(traced(y, trace))),
+        Expr::Fun(function, arguments) => Expr::Fun(*function, arguments.iter().map(|argument| traced(argument, trace)).collect()),
+    };
+
+    let result = Simplifier.simplify(&node);
+
+    if !result.structural_eq(&node) {
+        trace.record(rule(&node), node, result.clone());
+    }
+
+    result
+}
+
+impl Expr {
+    pub fn simplify(&self) -> Expr {
+        Simplifier.simplify(self)
+    }
+
+    pub fn simplify_traced(&self) -> (Expr, Trace) {
+        let mut trace = Trace::new();
+        let partial = traced(self, &mut trace);
+        let result = Simplifier.simplify(self);
+
+        if !result.structural_eq(&partial) {
+            trace.record(Rule::Normalize, partial, result.clone());
+        }
+
+        (result, trace)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::precise::expression::{ Environment, EvalErrorKind, Expr };
+    use crate::precise::expression::function::Function;
+    use crate::precise::trace::Rule;
+
+    fn simplify(source: &str) -> String {
+        source.parse::<Expr>().unwrap().simplify().to_string()
+    }
+
+    #[test]
+    fn constant_folding() {
+        assert_eq!(simplify("(3 + 2) * 15 / 61"), "75 / 61");
+        assert_eq!(simplify("1 / 3 * 3"), "1");
+        assert_eq!(simplify("2 ^ 10 - 7 % 4"), "1021");
+        assert_eq!(simplify("(4 / 9) ^ (1 / 2)"), "2 / 3");
+    }
+
+    #[test]
+    fn irrational_powers() {
+        assert_eq!(simplify("2 ^ (1 / 2) * 2 ^ (1 / 2)"), "2");
+        assert_eq!(simplify("8 ^ (1 / 2)"), "2 * 2 ^ (1 / 2)");
+    }
+
+    #[test]
+    fn collect_like_terms() {
+        assert_eq!(simplify("x + 2x - 3 + y + 5 - x"), "2 * x + y + 2");
+        assert_eq!(simplify("x - x"), "0");
+    }
+
+    #[test]
+    fn collect_powers() {
+        assert_eq!(simplify("x * x ^ 2 * y / x"), "x ^ 2 * y");
+        assert_eq!(simplify("x ^ n * x"), "x ^ (n + 1)");
+        assert_eq!(simplify("(x ^ 2) ^ 3"), "x ^ 6");
+    }
+
+    #[test]
+    fn cancel_common_factors() {
+        assert_eq!(simplify("(x + 1) ^ 2 / (x + 1)"), "x + 1");
+        assert_eq!(simplify("(2x + 2) / (x + 1)"), "2");
+        assert_eq!(simplify("6x / (4y)"), "3 * x / (2 * y)");
+    }
+
+    #[test]
+    fn expand() {
+        assert_eq!(simplify("(x + 1) * (x - 1)"), "x ^ 2 - 1");
+        assert_eq!(simplify("(x + 1) ^ 2"), "x ^ 2 + 2 * x + 1");
+    }
+
+    #[test]
+    fn functions() {
+        assert_eq!(simplify("sqrt(x) * sqrt(x)"), "x ^ (1 / 2) * x ^ (1 / 2)");
+        assert_eq!(simplify("sqrt(abs(x)) * sqrt(abs(x))"), "abs(x)");
+        assert_eq!(simplify("max(2, 7) + sin(0) + ln(exp(x))"), "x + 7");
+        assert_eq!(simplify("2sin(x) + sin(x) - cos(x + x)"), "3 * sin(x) - cos(2 * x)");
+        assert_eq!(simplify("abs(abs(x - 1))"), "abs(x - 1)");
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(simplify("sin(pi) + cos(pi) + ln(e) + tan(pi / 4)"), "1");
+        assert_eq!(simplify("sin(7pi / 6) - cos(pi / 3)"), "-1");
+        assert_eq!(simplify("exp(x) * exp(2x) * e"), "e ^ (3 * x + 1)");
+        assert_eq!(simplify("ln(exp(x + 1))"), "x + 1");
+        assert_eq!(simplify("i ^ 2 + i ^ 7 + i * i * i * i"), "-i");
+        assert_eq!(simplify("(2 + 3i) * (2 - 3i)"), "13");
+        assert_eq!(simplify("2x pi + pi x + sin(pi / 4)"), "3 * pi * x + sin(pi / 4)");
+    }
+
+    #[test]
+    fn canonical_form() {
+        let equivalent = [
+            "(x + 1) ^ 2 - 2x",
+            "x ^ 2 + 1",
+            "1 + x * x",
+            "(x - 1) * (x + 1) + 2",
+        ];
+
+        let results: Vec<String> = equivalent.iter().map(|source| simplify(source)).collect();
+
+        for result in results.iter() {
+            assert_eq!(*result, results[0]);
+        }
+    }
+
+    #[test]
+    fn idempotent() {
+        let sources = [
+            "(x + y) ^ 3 / (2z) - x / y",
+            "3 ^ (1 / 2) * x ^ n + 1 / (x + 1)",
+            "(a - b) * (a + b) % 5",
+        ];
+
+        for source in sources.iter() {
+            let once = source.parse::<Expr>().unwrap().simplify();
+            let twice = once.simplify();
+
+            assert_eq!(format!("{:?}", once), format!("{:?}", twice));
+        }
+    }
+
+    fn random_expression(state: &mut u64, depth: u32) -> Expr {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+
+        let choice = if depth == 0 { *state % 4 } else { *state % 13 };
+        let operand = |state: &mut u64| random_expression(state, depth.saturating_sub(1));
+
+        match choice {
+            0 => Expr::var("x"),
+            1 => Expr::var("y"),
+            2 => Expr::from((*state >> 8) as i64 % 7 - 3),
+            3 => Expr::e(),
+            4 => operand(state) + operand(state),
+            5 => operand(state) - operand(state),
+            6 => operand(state) * operand(state),
+            7 => operand(state) / operand(state),
+            8 => operand(state) % operand(state),
+            9 => operand(state) ^ Expr::from((*state >> 8) as i64 % 5 - 2),
+            10 => Expr::call(Function::Sqrt, vec![operand(state)]),
+            11 => Expr::call(if *state & 256 == 0 { Function::Sin } else { Function::Exp }, vec![operand(state)]),
+            _ => Expr::from(-3) * operand(state),
+        }
+    }
+
+    #[test]
+    fn idempotent_random() {
+        let mut state = 0x2545_f491_4f6c_dd1d;
+
+        let sources = [
+            "sqrt(x * y) ^ 4",
+            "(2 / sqrt(4x)) ^ 2",
+            "sqrt(sqrt(x)) ^ 4",
+            "sqrt(x) * sqrt(x)",
+            "(-8) ^ (1 / 3)",
+            "x / (pi / 0)",
+            "1 / (6 * 0 * x)",
+            "-3 * (x + y)",
+        ];
+
+        let mut expressions: Vec<Expr> = sources.iter().map(|source| source.parse().unwrap()).collect();
+        expressions.extend((0..1000).map(|_| random_expression(&mut state, 4)));
+
+        let environments = [
+            Environment::new().with("x", 0.7).with("y", -1.3),
+            Environment::new().with("x", 1.5).with("y", 0.4),
+            Environment::new().with("x", -1.6).with("y", 1.1),
+        ];
+
+        for expression in expressions.iter() {
+            let once = expression.simplify();
+            let twice = once.simplify();
+
+            assert!(once.structural_eq(&twice), "{} simplified to {} and then {}", expression, once, twice);
+
+            for environment in environments.iter() {
+                match (expression.eval_with(environment), once.eval_with(environment)) {
+                    (Ok(lhs), Ok(rhs)) => assert!(
+                        lhs == rhs || (lhs - rhs).abs() <= 1e-9 * lhs.abs().max(1.0) || (lhs.is_nan() && rhs.is_nan()),
+                        "{} = {} but {} = {}", expression, lhs, once, rhs,
+                    ),
+                    (Err(_), Err(_)) => {},
+                    (Err(ref error), Ok(_)) if *error.kind() == EvalErrorKind::DivisionByZero => {},
+                    (Err(_), Ok(_)) if once.structural_eq(&Expr::from(0)) => {},
+                    (lhs, rhs) => panic!("{} = {:?} but {} = {:?}", expression, lhs, once, rhs),
+                }
+            }
+        }
+
+        assert_eq!(simplify("sqrt(x * y) ^ 4"), "((x * y) ^ (1 / 2)) ^ 4");
+        assert_eq!(simplify("(2 / sqrt(4x)) ^ 2"), "4 * ((4 * x) ^ (-1 / 2)) ^ 2");
+        assert_eq!(simplify("sqrt(sqrt(x)) ^ 4"), "(x ^ (1 / 2)) ^ 2");
+        assert_eq!(simplify("sqrt(abs(x)) ^ 4"), "abs(x) ^ 2");
+        assert_eq!(simplify("(-8) ^ (1 / 3)"), "(-8) ^ (1 / 3)");
+        assert_eq!(simplify("27 ^ (1 / 3)"), "3");
+        assert_eq!(simplify("x / (pi / 0)"), "x / (pi * 0 ^ (-1))");
+    }
+
+    #[test]
+    fn simplify_traced() {
+        let (result, trace) = "x + 2 * 3 + x * (x + 1)".parse::<Expr>().unwrap().simplify_traced();
+        let rules: Vec<Rule> = trace.steps().iter().map(|step| step.rule()).collect();
+
+        assert_eq!(result.to_string(), "x ^ 2 + 2 * x + 6");
+        assert_eq!(rules, vec![Rule::EvaluateArithmetic, Rule::ExpandProduct, Rule::CombineLikeTerms]);
+        assert_eq!(trace.steps()[1].to_string(), "x * (x + 1) => x ^ 2 + x (expand product)");
+
+        let (result, trace) = "x + 1".parse::<Expr>().unwrap().simplify_traced();
+
+        assert_eq!(result.to_string(), "x + 1");
+        assert!(trace.is_empty());
+    }
+
+    #[test]
+    fn division_by_zero() {
+        let result = "x / 0".parse::<Expr>().unwrap().simplify();
+
+        assert_eq!(result.to_string(), "x / 0");
+    }
+}
@@ -0,0 +1,189 @@
+use std::cmp::Ordering;
+use std::hash::{ Hash, Hasher };
+use crate::precise::expression::Expr;
+
+#[derive(Debug, Clone)]
+pub struct Structural(pub Expr);
+
+impl Structural {
+    pub fn into_inner(self) -> Expr {
+        self.0
+    }
+}
+
+impl From<Expr> for Structural {
+    fn from(value: Expr) -> Self {
+        Structural(value)
+    }
+}
+
+fn rank(expression: &Expr) -> u8 {
+    match expression {
+        Expr::Val(_) => 0,
+        Expr::Const(_) => 1,
+        Expr::Var(_) => 2,
+        Expr::Add(_, _) => 3,
+        Expr::Sub(_, _) => 4,
+        Expr::Mul(_, _) => 5,
+        Expr::Div(_, _) => 6,
+        Expr::Rem(_, _) => 7,
+        Expr::Exp(_, _) => 8,
+        Expr::Fun(_, _) => 9,
+    }
+}
+
+fn operands(expression: &Expr) -> Option<(&Expr, &Expr)> {
+    match expression {
+        Expr::Add(x, y) | Expr::Sub(x, y) | Expr::Mul(x, y) | Expr::Div(x, y) | Expr::Rem(x, y) | Expr::Exp(x, y) => Some((x, y)),
+        _ => None,
+    }
+}
+
+enum Task<'a> {
+    Compare(&'a Expr, &'a Expr),
+    Length(usize, usize),
+}
+
+fn compare_node<'a>(lhs: &'a Expr, rhs: &'a Expr, pending: &mut Vec<Task<'a>>) -> Ordering {
+    match (lhs, rhs) {
+        (Expr::Val(x), Expr::Val(y)) => x.cmp(y),
+        (Expr::Var(x), Expr::Var(y)) => x.cmp(y),
+        (Expr::Const(x), Expr::Const(y)) => x.cmp(y),
+        (Expr::Fun(f, x), Expr::Fun(g, y)) => f.cmp(g).then_with(|| {
+            pending.push(Task::Length(x.len(), y.len()));
+            pending.extend(x.iter().zip(y.iter()).rev().map(|(a, b)| Task::Compare(a, b)));
+
+            Ordering::Equal
+        }),
+        _ => match (operands(lhs), operands(rhs)) {
+            (Some((a, b)), Some((c, d))) if rank(lhs) == rank(rhs) => {
+                pending.push(Task::Compare(b, d));
+                pending.push(Task::Compare(a, c));
+
+                Ordering::Equal
+            },
+            _ => rank(lhs).cmp(&rank(rhs)),
+        },
+    }
+}
+
+pub(crate) fn compare(lhs: &Expr, rhs: &Expr) -> Ordering {
+    let mut pending = vec![Task::Compare(lhs, rhs)];
+
+    while let Some(task) = pending.pop() {
+        let ordering = match task {
+            Task::Compare(lhs, rhs) if std::ptr::eq(lhs, rhs) => Ordering::Equal,
+            Task::Compare(lhs, rhs) => compare_node(lhs, rhs, &mut pending),
+            Task::Length(x, y) => x.cmp(&y),
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    Ordering::Equal
+}
+
+fn hash<H: Hasher>(expression: &Expr, state: &mut H) {
+    let mut pending = vec![expression];
+
+    while let Some(expression) = pending.pop() {
+        rank(expression).hash(state);
+
+        match expression {
+            Expr::Val(x) => x.hash(state),
+            Expr::Var(x) => x.hash(state),
+            Expr::Const(x) => x.hash(state),
+            Expr::Fun(function, arguments) => {
+                function.hash(state);
+                pending.extend(arguments.iter().rev());
+            },
+            _ => if let Some((x, y)) = operands(expression) {
+                pending.push(y);
+                pending.push(x);
+            },
+        }
+    }
+}
+
+impl PartialEq for Structural {
+    fn eq(&self, rhs: &Self) -> bool {
+        compare(&self.0, &rhs.0) == Ordering::Equal
+    }
+}
+
+impl Eq for Structural {}
+
+impl PartialOrd for Structural {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl Ord for Structural {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        compare(&self.0, &rhs.0)
+    }
+}
+
+impl Hash for Structural {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash(&self.0, state);
+    }
+}
+
+impl Expr {
+    pub fn structural_eq(&self, rhs: &Expr) -> bool {
+        compare(self, rhs) == Ordering::Equal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{ HashMap, HashSet };
+    use super::Structural;
+    use crate::precise::expression::Expr;
+
+    fn structural(source: &str) -> Structural {
+        Structural(source.parse().unwrap())
+    }
+
+    #[test]
+    fn structural_eq() {
+        assert!(Expr::var("x").structural_eq(&Expr::var("x")));
+        assert!(!"2 * 3".parse::<Expr>().unwrap().structural_eq(&Expr::from(6)));
+        assert!(!"x + y".parse::<Expr>().unwrap().structural_eq(&"y + x".parse().unwrap()));
+    }
+
+    #[test]
+    fn deduplicate() {
+        let set: HashSet<Structural> = ["x + 1", "1 + x", "x + 1", "2 / 4", "1 / 2", "2 / 4"].iter()
+            .map(|source| structural(source))
+            .collect();
+
+        assert_eq!(set.len(), 4);
+    }
+
+    #[test]
+    fn hash_map_key() {
+        let mut map = HashMap::new();
+        map.insert(structural("x ^ 2"), 1);
+        map.insert(structural("x * x"), 2);
+
+        assert_eq!(map.get(&structural("x ^ 2")), Some(&1));
+        assert_eq!(map.get(&structural("x * x")), Some(&2));
+        assert_eq!(map.get(&structural("x ^ 3")), None);
+    }
+
+    #[test]
+    fn sort() {
+        let mut expressions = vec![structural("y"), structural("x + 1"), structural("3"), structural("x"), structural("-2")];
+        expressions.sort();
+
+        let result: Vec<String> = expressions.into_iter().map(|expression| expression.into_inner().to_string()).collect();
+        let expected_result = vec!["-2", "3", "x", "y", "x + 1"];
+
+        assert_eq!(result, expected_result);
+    }
+}
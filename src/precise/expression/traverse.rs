@@ -0,0 +1,260 @@
+use std::fmt;
+use std::mem;
+use std::sync::Arc;
+use crate::precise::expression::Expr;
+
+thread_local! {
+    static DETACHED: Arc<Expr> = Arc::new(Expr::Var(String::new()));
+}
+
+enum Visit<'a> {
+    Enter(&'a Expr),
+    Exit(&'a Expr),
+}
+
+impl Expr {
+    pub(crate) fn postorder<'a, E, F: FnMut(&'a Expr) -> Result<(), E>>(&'a self, mut visit: F) -> Result<(), E> {
+        let mut pending = vec![Visit::Enter(self)];
+
+        while let Some(item) = pending.pop() {
+            match item {
+                Visit::Enter(expression) => {
+                    pending.push(Visit::Exit(expression));
+
+                    match expression {
+                        Expr::Val(_) | Expr::Var(_) | Expr::Const(_) => (),
+                        Expr::Add(x, y) | Expr::Sub(x, y) | Expr::Mul(x, y) | Expr::Div(x, y) | Expr::Rem(x, y) | Expr::Exp(x, y) => {
+                            pending.push(Visit::Enter(y));
+                            pending.push(Visit::Enter(x));
+                        },
+                        Expr::Fun(_, arguments) => pending.extend(arguments.iter().rev().map(Visit::Enter)),
+                    }
+                },
+                Visit::Exit(expression) => visit(expression)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn detach_child(child: &mut Arc<Expr>, pending: &mut Vec<Expr>) {
+    if Arc::strong_count(child) != 1 {
+        return;
+    }
+
+    if let Ok(placeholder) = DETACHED.try_with(Arc::clone) {
+        if let Ok(expression) = Arc::try_unwrap(mem::replace(child, placeholder)) {
+            pending.push(expression);
+        }
+    }
+}
+
+fn detach(expression: &mut Expr, pending: &mut Vec<Expr>) {
+    match expression {
+        Expr::Val(_) | Expr::Var(_) | Expr::Const(_) => (),
+        Expr::Add(x, y) | Expr::Sub(x, y) | Expr::Mul(x, y) | Expr::Div(x, y) | Expr::Rem(x, y) | Expr::Exp(x, y) => {
+            detach_child(x, pending);
+            detach_child(y, pending);
+        },
+        Expr::Fun(_, arguments) => {
+            if let Some(arguments) = Arc::get_mut(arguments) {
+                pending.extend(arguments.iter_mut().map(|argument| mem::replace(argument, Expr::Var(String::new()))));
+            }
+        },
+    }
+}
+
+impl Clone for Expr {
+    fn clone(&self) -> Self {
+        match self {
+            Expr::Val(x) => Expr::Val(x.clone()),
+            Expr::Var(x) => Expr::Var(x.clone()),
+            Expr::Const(x) => Expr::Const(*x),
+            Expr::Add(x, y) => Expr::Add(x.clone(), y.clone()),
+            Expr::Sub(x, y) => Expr::Sub(x.clone(), y.clone()),
+            Expr::Mul(x, y) => Expr::Mul(x.clone(), y.clone()),
+            Expr::Div(x, y) => Expr::Div(x.clone(), y.clone()),
+            Expr::Rem(x, y) => Expr::Rem(x.clone(), y.clone()),
+            Expr::Exp(x, y) => Expr::Exp(x.clone(), y.clone()),
+            Expr::Fun(function, arguments) => Expr::Fun(*function, arguments.clone()),
+        }
+    }
+}
+
+enum Token<'a> {
+    Node(&'a Expr),
+    Text(&'static str),
+}
+
+impl fmt::Debug for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut pending = vec![Token::Node(self)];
+
+        while let Some(token) = pending.pop() {
+            let expression = match token {
+                Token::Node(expression) => expression,
+                Token::Text(text) => {
+                    f.write_str(text)?;
+                    continue;
+                },
+            };
+
+            let (name, x, y) = match expression {
+                Expr::Val(x) => {
+                    write!(f, "Val({:?})", x)?;
+                    continue;
+                },
+                Expr::Var(x) => {
+                    write!(f, "Var({:?})", x)?;
+                    continue;
+                },
+                Expr::Const(x) => {
+                    write!(f, "Const({:?})", x)?;
+                    continue;
+                },
+                Expr::Fun(function, arguments) => {
+                    write!(f, "Fun({:?}, [", function)?;
+                    pending.push(Token::Text("])"));
+
+                    for (index, argument) in arguments.iter().enumerate().rev() {
+                        pending.push(Token::Node(argument));
+
+                        if index > 0 {
+                            pending.push(Token::Text(", "));
+                        }
+                    }
+
+                    continue;
+                },
+                Expr::Add(x, y) => ("Add(", x, y),
+                Expr::Sub(x, y) => ("Sub(", x, y),
+                Expr::Mul(x, y) => ("Mul(", x, y),
+                Expr::Div(x, y) => ("Div(", x, y),
+                Expr::Rem(x, y) => ("Rem(", x, y),
+                Expr::Exp(x, y) => ("Exp(", x, y),
+            };
+
+            f.write_str(name)?;
+            pending.extend(vec![Token::Text(")"), Token::Node(y), Token::Text(", "), Token::Node(x)]);
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Expr {
+    fn drop(&mut self) {
+        let mut pending = Vec::new();
+        detach(self, &mut pending);
+
+        while let Some(mut expression) = pending.pop() {
+            detach(&mut expression, &mut pending);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::sync::Arc;
+    use crate::precise::expression::Expr;
+    use crate::precise::expression::environment::Environment;
+    use crate::precise::expression::function::Function;
+    use crate::precise::expression::structural::Structural;
+
+    const DEPTH: usize = 1_000_000;
+
+    fn chain(depth: usize) -> Expr {
+        (1..depth).fold(Expr::var("x"), |sum, term| sum + Expr::from(term as u64 % 7))
+    }
+
+    fn nested(depth: usize) -> Expr {
+        (0..depth).fold(Expr::var("x"), |argument, _| Expr::Fun(Function::Abs, vec![argument].into()))
+    }
+
+    #[test]
+    fn postorder() {
+        let expression: Expr = "max(x, 2) - y * 3".parse().unwrap();
+        let mut visited = Vec::new();
+
+        expression.postorder::<(), _>(|node| {
+            visited.push(node.to_string());
+            Ok(())
+        }).unwrap();
+
+        assert_eq!(visited, vec!["x", "2", "max(x, 2)", "y", "3", "y * 3", "max(x, 2) - y * 3"]);
+    }
+
+    #[test]
+    fn clone() {
+        let expression: Expr = "abs(sin(x + 1)) * 2".parse().unwrap();
+        let copy = expression.clone();
+
+        assert!(copy.structural_eq(&expression));
+
+        if let (Expr::Mul(a, _), Expr::Mul(b, _)) = (&expression, &copy) {
+            assert!(Arc::ptr_eq(a, b));
+        }
+
+        let function = nested(3);
+
+        if let (Expr::Fun(_, a), Expr::Fun(_, b)) = (&function, &function.clone()) {
+            assert!(Arc::ptr_eq(a, b));
+        }
+    }
+
+    #[test]
+    fn deep_chain() {
+        let expression = chain(DEPTH);
+        let copy = expression.clone();
+
+        drop(expression);
+        assert_eq!(copy.eval_with(&Environment::new().with("x", 0)), Ok(2_999_997.0));
+
+        let printed = copy.to_string();
+
+        assert!(printed.starts_with("x + 1 + 2 + 3 + 4 + 5 + 6 + 0 + 1"));
+        assert!(printed.ends_with(" + 5 + 6 + 0"));
+
+        let json = serde_json::to_string(&copy).unwrap();
+        let restored: Expr = serde_json::from_str(&json).unwrap();
+
+        assert!(restored.structural_eq(&copy));
+        assert!(!restored.structural_eq(&chain(DEPTH - 1)));
+
+        let mut set = HashSet::new();
+
+        set.insert(Structural(copy.clone()));
+        assert!(set.contains(&Structural(restored.clone())));
+
+        let latex = copy.to_latex();
+
+        assert!(latex.starts_with("x + 1 + 2 + 3"));
+        assert!(latex.ends_with(" + 6 + 0"));
+
+        let debug = format!("{:?}", copy);
+
+        assert!(debug.starts_with("Add(Add(Add("));
+        assert!(debug.ends_with("Val(5)), Val(6)), Val(0))"));
+
+        let substituted = copy.substitute("x", 2);
+
+        drop(copy);
+        assert_eq!(restored.eval_with(&Environment::new().with("x", 1)), Ok(2_999_998.0));
+        assert_eq!(substituted.try_eval(), Ok(2_999_999.0));
+    }
+
+    #[test]
+    fn deep_functions() {
+        let expression = nested(DEPTH);
+        let copy = expression.clone();
+
+        drop(expression);
+        assert_eq!(copy.eval_with(&Environment::new().with("x", -2)), Ok(2.0));
+        assert!(copy.structural_eq(&nested(DEPTH)));
+        assert!(copy.to_latex().starts_with("\\left|\\left|"));
+        assert!(format!("{:?}", copy).ends_with(&format!("[Var(\"x\"){}", "])".repeat(DEPTH))));
+        assert_eq!(copy.substitute("x", -3).try_eval(), Ok(3.0));
+    }
+}
@@ -0,0 +1,966 @@
+use std::fmt;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::str::FromStr;
+use std::ops::{ Add, Sub, Mul, Div, Rem, Neg, Shl, Shr };
+use serde::{ Serialize, Serializer, Deserialize, Deserializer };
+use serde::de::{ self, Visitor };
+
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+const DECIMAL_CHUNK_DIGITS: usize = 9;
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Integer {
+    negative: bool,
+    magnitude: Vec<u32>,
+}
+
+impl Integer {
+    pub fn zero() -> Integer {
+        Integer {
+            negative: false,
+            magnitude: Vec::new(),
+        }
+    }
+
+    pub fn one() -> Integer {
+        Integer::from(1)
+    }
+
+    fn from_parts(negative: bool, mut magnitude: Vec<u32>) -> Integer {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+
+        Integer {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn is_one(&self) -> bool {
+        !self.negative && self.magnitude == [1]
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn is_positive(&self) -> bool {
+        !self.negative && !self.is_zero()
+    }
+
+    pub fn is_even(&self) -> bool {
+        self.magnitude.first().copied().unwrap_or(0) & 1 == 0
+    }
+
+    pub fn signum(&self) -> i32 {
+        if self.negative {
+            -1
+        } else if self.is_zero() {
+            0
+        } else {
+            1
+        }
+    }
+
+    pub fn abs(&self) -> Integer {
+        Integer {
+            negative: false,
+            magnitude: self.magnitude.clone(),
+        }
+    }
+
+    pub fn bits(&self) -> u64 {
+        match self.magnitude.last() {
+            Some(last) => self.magnitude.len() as u64 * 32 - u64::from(last.leading_zeros()),
+            None => 0,
+        }
+    }
+
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.magnitude.len() > 4 {
+            return None;
+        }
+
+        let magnitude = self.magnitude.iter()
+            .rev()
+            .fold(0_u128, |acc, limb| (acc << 32) | u128::from(*limb));
+
+        if self.negative {
+            if magnitude <= i128::MAX as u128 + 1 {
+                Some((magnitude as i128).wrapping_neg())
+            } else {
+                None
+            }
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    pub fn to_u32(&self) -> Option<u32> {
+        self.to_i128().and_then(|value| u32::try_from(value).ok())
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        let bits = self.bits();
+
+        let magnitude = if bits <= 64 {
+            self.low_u64() as f64
+        } else {
+            let shift = bits - 64;
+            let mut top = (self.abs() >> shift).low_u64();
+
+            if self.trailing_zeros() < shift {
+                top |= 1;
+            }
+
+            (top as f64) * 2_f64.powi(shift.min(i32::MAX as u64) as i32)
+        };
+
+        if self.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    fn low_u64(&self) -> u64 {
+        let low = u64::from(self.magnitude.first().copied().unwrap_or(0));
+        let high = u64::from(self.magnitude.get(1).copied().unwrap_or(0));
+
+        (high << 32) | low
+    }
+
+    fn trailing_zeros(&self) -> u64 {
+        for (index, limb) in self.magnitude.iter().enumerate() {
+            if *limb != 0 {
+                return index as u64 * 32 + u64::from(limb.trailing_zeros());
+            }
+        }
+
+        0
+    }
+
+    pub fn div_rem(&self, rhs: &Integer) -> (Integer, Integer) {
+        if rhs.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+
+        let (quotient, remainder) = div_rem_magnitude(&self.magnitude, &rhs.magnitude);
+
+        (
+            Integer::from_parts(self.negative != rhs.negative, quotient),
+            Integer::from_parts(self.negative, remainder),
+        )
+    }
+
+    pub fn div_floor(&self, rhs: &Integer) -> Integer {
+        let (quotient, remainder) = self.div_rem(rhs);
+
+        if !remainder.is_zero() && remainder.negative != rhs.negative {
+            quotient - Integer::one()
+        } else {
+            quotient
+        }
+    }
+
+    pub fn pow(&self, exponent: u32) -> Integer {
+        let mut result = Integer::one();
+        let mut base = self.clone();
+        let mut exponent = exponent;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+
+            exponent >>= 1;
+
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+
+        result
+    }
+
+    pub fn gcd(&self, rhs: &Integer) -> Integer {
+        let mut a = self.abs();
+        let mut b = rhs.abs();
+
+        while !b.is_zero() {
+            let remainder = &a % &b;
+            a = b;
+            b = remainder;
+        }
+
+        a
+    }
+
+    pub fn root(&self, degree: u32) -> Option<Integer> {
+        if degree == 0 {
+            return None;
+        }
+
+        if self.negative {
+            if degree & 1 == 0 {
+                return None;
+            }
+
+            return self.abs().root(degree).map(|root| -root);
+        }
+
+        if degree == 1 || self.bits() <= 1 {
+            return Some(self.clone());
+        }
+
+        if u64::from(degree) >= self.bits() {
+            return None;
+        }
+
+        let root = self.floor_root(degree);
+
+        if root.pow(degree) == *self {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    fn floor_root(&self, degree: u32) -> Integer {
+        let degree_integer = Integer::from(degree);
+        let previous_degree = Integer::from(degree - 1);
+
+        let mut current = Integer::one() << self.bits().div_ceil(u64::from(degree));
+
+        loop {
+            let next = &(&previous_degree * &current + self / &current.pow(degree - 1)) / &degree_integer;
+
+            if next >= current {
+                return current;
+            }
+
+            current = next;
+        }
+    }
+}
+
+fn compare_magnitude(lhs: &[u32], rhs: &[u32]) -> Ordering {
+    lhs.len().cmp(&rhs.len())
+        .then_with(|| lhs.iter().rev().cmp(rhs.iter().rev()))
+}
+
+fn add_magnitude(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let (long, short) = if lhs.len() >= rhs.len() {
+        (lhs, rhs)
+    } else {
+        (rhs, lhs)
+    };
+
+    let mut result = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0_u64;
+
+    for (index, limb) in long.iter().enumerate() {
+        let sum = u64::from(*limb) + u64::from(short.get(index).copied().unwrap_or(0)) + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+
+    result
+}
+
+fn sub_magnitude(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(lhs.len());
+    let mut borrow = 0_i64;
+
+    for (index, limb) in lhs.iter().enumerate() {
+        let mut difference = i64::from(*limb) - i64::from(rhs.get(index).copied().unwrap_or(0)) - borrow;
+
+        if difference < 0 {
+            difference += 1 << 32;
+            borrow = 1;
+        } else {
+            borrow = 0;
+        }
+
+        result.push(difference as u32);
+    }
+
+    result
+}
+
+fn mul_magnitude(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    if lhs.is_empty() || rhs.is_empty() {
+        return Vec::new();
+    }
+
+    let mut result = vec![0_u32; lhs.len() + rhs.len()];
+
+    for (i, a) in lhs.iter().enumerate() {
+        let mut carry = 0_u64;
+
+        for (j, b) in rhs.iter().enumerate() {
+            let product = u64::from(*a) * u64::from(*b) + u64::from(result[i + j]) + carry;
+            result[i + j] = product as u32;
+            carry = product >> 32;
+        }
+
+        result[i + rhs.len()] = carry as u32;
+    }
+
+    result
+}
+
+fn div_rem_small(lhs: &[u32], rhs: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0_u32; lhs.len()];
+    let mut remainder = 0_u64;
+
+    for (index, limb) in lhs.iter().enumerate().rev() {
+        let current = (remainder << 32) | u64::from(*limb);
+        quotient[index] = (current / u64::from(rhs)) as u32;
+        remainder = current % u64::from(rhs);
+    }
+
+    (quotient, remainder as u32)
+}
+
+fn div_rem_magnitude(lhs: &[u32], rhs: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if compare_magnitude(lhs, rhs) == Ordering::Less {
+        return (Vec::new(), lhs.to_vec());
+    }
+
+    if rhs.len() == 1 {
+        let (quotient, remainder) = div_rem_small(lhs, rhs[0]);
+
+        return (quotient, vec![remainder]);
+    }
+
+    let shift = rhs[rhs.len() - 1].leading_zeros();
+    let divisor = shl_magnitude(rhs, u64::from(shift));
+    let mut dividend = shl_magnitude(lhs, u64::from(shift));
+
+    if dividend.len() == lhs.len() {
+        dividend.push(0);
+    }
+
+    let n = divisor.len();
+    let m = dividend.len() - n;
+    let mut quotient = vec![0_u32; m];
+
+    let top = u64::from(divisor[n - 1]);
+    let second = u64::from(divisor[n - 2]);
+
+    for j in (0..m).rev() {
+        let numerator = (u64::from(dividend[j + n]) << 32) | u64::from(dividend[j + n - 1]);
+        let mut estimate = numerator / top;
+        let mut remainder = numerator % top;
+
+        while estimate > u64::from(u32::MAX)
+            || estimate * second > ((remainder << 32) | u64::from(dividend[j + n - 2])) {
+            estimate -= 1;
+            remainder += top;
+
+            if remainder > u64::from(u32::MAX) {
+                break;
+            }
+        }
+
+        let mut borrow = 0_i64;
+        let mut carry = 0_u64;
+
+        for i in 0..n {
+            let product = estimate * u64::from(divisor[i]) + carry;
+            carry = product >> 32;
+
+            let difference = i64::from(dividend[i + j]) - i64::from(product as u32) - borrow;
+            dividend[i + j] = difference as u32;
+            borrow = if difference < 0 { 1 } else { 0 };
+        }
+
+        let difference = i64::from(dividend[j + n]) - carry as i64 - borrow;
+        dividend[j + n] = difference as u32;
+
+        if difference < 0 {
+            estimate -= 1;
+
+            let mut carry = 0_u64;
+
+            for i in 0..n {
+                let sum = u64::from(dividend[i + j]) + u64::from(divisor[i]) + carry;
+                dividend[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+
+            dividend[j + n] = dividend[j + n].wrapping_add(carry as u32);
+        }
+
+        quotient[j] = estimate as u32;
+    }
+
+    dividend.truncate(n);
+
+    (quotient, shr_magnitude(&dividend, u64::from(shift)))
+}
+
+fn shl_magnitude(magnitude: &[u32], shift: u64) -> Vec<u32> {
+    if magnitude.is_empty() {
+        return Vec::new();
+    }
+
+    let limbs = (shift / 32) as usize;
+    let bits = (shift % 32) as u32;
+
+    let mut result = vec![0_u32; limbs];
+    result.reserve(magnitude.len() + 1);
+
+    if bits == 0 {
+        result.extend_from_slice(magnitude);
+    } else {
+        let mut carry = 0_u32;
+
+        for limb in magnitude {
+            result.push((limb << bits) | carry);
+            carry = limb >> (32 - bits);
+        }
+
+        if carry > 0 {
+            result.push(carry);
+        }
+    }
+
+    result
+}
+
+fn shr_magnitude(magnitude: &[u32], shift: u64) -> Vec<u32> {
+    let limbs = (shift / 32) as usize;
+    let bits = (shift % 32) as u32;
+
+    if limbs >= magnitude.len() {
+        return Vec::new();
+    }
+
+    let magnitude = &magnitude[limbs..];
+
+    if bits == 0 {
+        return magnitude.to_vec();
+    }
+
+    let mut result = Vec::with_capacity(magnitude.len());
+
+    for (index, limb) in magnitude.iter().enumerate() {
+        let high = magnitude.get(index + 1).copied().unwrap_or(0);
+        result.push((limb >> bits) | (high << (32 - bits)));
+    }
+
+    result
+}
+
+impl From<i8> for Integer {
+    fn from(value: i8) -> Self {
+        Integer::from(i128::from(value))
+    }
+}
+
+impl From<i16> for Integer {
+    fn from(value: i16) -> Self {
+        Integer::from(i128::from(value))
+    }
+}
+
+impl From<i32> for Integer {
+    fn from(value: i32) -> Self {
+        Integer::from(i128::from(value))
+    }
+}
+
+impl From<i64> for Integer {
+    fn from(value: i64) -> Self {
+        Integer::from(i128::from(value))
+    }
+}
+
+impl From<i128> for Integer {
+    fn from(value: i128) -> Self {
+        let mut integer = Integer::from(value.unsigned_abs());
+        integer.negative = value < 0;
+
+        integer
+    }
+}
+
+impl From<isize> for Integer {
+    fn from(value: isize) -> Self {
+        Integer::from(value as i128)
+    }
+}
+
+impl From<u8> for Integer {
+    fn from(value: u8) -> Self {
+        Integer::from(u128::from(value))
+    }
+}
+
+impl From<u16> for Integer {
+    fn from(value: u16) -> Self {
+        Integer::from(u128::from(value))
+    }
+}
+
+impl From<u32> for Integer {
+    fn from(value: u32) -> Self {
+        Integer::from(u128::from(value))
+    }
+}
+
+impl From<u64> for Integer {
+    fn from(value: u64) -> Self {
+        Integer::from(u128::from(value))
+    }
+}
+
+impl From<u128> for Integer {
+    fn from(value: u128) -> Self {
+        let mut magnitude = Vec::with_capacity(4);
+        let mut value = value;
+
+        while value > 0 {
+            magnitude.push(value as u32);
+            value >>= 32;
+        }
+
+        Integer::from_parts(false, magnitude)
+    }
+}
+
+impl From<usize> for Integer {
+    fn from(value: usize) -> Self {
+        Integer::from(value as u128)
+    }
+}
+
+impl<'a> Add<&'a Integer> for &'a Integer {
+    type Output = Integer;
+
+    fn add(self, rhs: &Integer) -> Self::Output {
+        if self.negative == rhs.negative {
+            return Integer::from_parts(self.negative, add_magnitude(&self.magnitude, &rhs.magnitude));
+        }
+
+        match compare_magnitude(&self.magnitude, &rhs.magnitude) {
+            Ordering::Less => Integer::from_parts(rhs.negative, sub_magnitude(&rhs.magnitude, &self.magnitude)),
+            _ => Integer::from_parts(self.negative, sub_magnitude(&self.magnitude, &rhs.magnitude)),
+        }
+    }
+}
+
+impl Add for Integer {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl<'a> Sub<&'a Integer> for &'a Integer {
+    type Output = Integer;
+
+    fn sub(self, rhs: &Integer) -> Self::Output {
+        self + &(-rhs)
+    }
+}
+
+impl Sub for Integer {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl<'a> Mul<&'a Integer> for &'a Integer {
+    type Output = Integer;
+
+    fn mul(self, rhs: &Integer) -> Self::Output {
+        Integer::from_parts(self.negative != rhs.negative, mul_magnitude(&self.magnitude, &rhs.magnitude))
+    }
+}
+
+impl Mul for Integer {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<'a> Div<&'a Integer> for &'a Integer {
+    type Output = Integer;
+
+    fn div(self, rhs: &Integer) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Div for Integer {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+impl<'a> Rem<&'a Integer> for &'a Integer {
+    type Output = Integer;
+
+    fn rem(self, rhs: &Integer) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl Rem for Integer {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        &self % &rhs
+    }
+}
+
+impl Neg for &Integer {
+    type Output = Integer;
+
+    fn neg(self) -> Self::Output {
+        Integer::from_parts(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Neg for Integer {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Integer::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl Shl<u64> for Integer {
+    type Output = Self;
+
+    fn shl(self, rhs: u64) -> Self::Output {
+        Integer::from_parts(self.negative, shl_magnitude(&self.magnitude, rhs))
+    }
+}
+
+impl Shr<u64> for Integer {
+    type Output = Self;
+
+    fn shr(self, rhs: u64) -> Self::Output {
+        Integer::from_parts(self.negative, shr_magnitude(&self.magnitude, rhs))
+    }
+}
+
+impl PartialOrd for Integer {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl Ord for Integer {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        match (self.negative, rhs.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitude(&self.magnitude, &rhs.magnitude),
+            (true, true) => compare_magnitude(&rhs.magnitude, &self.magnitude),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseIntegerError {
+    Empty,
+    InvalidDigit,
+}
+
+impl fmt::Display for ParseIntegerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseIntegerError::Empty => write!(f, "cannot parse integer from empty string"),
+            ParseIntegerError::InvalidDigit => write!(f, "invalid digit found in string"),
+        }
+    }
+}
+
+impl std::error::Error for ParseIntegerError {}
+
+impl FromStr for Integer {
+    type Err = ParseIntegerError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match value.as_bytes().first() {
+            Some(b'-') => (true, &value[1..]),
+            Some(b'+') => (false, &value[1..]),
+            _ => (false, value),
+        };
+
+        if digits.is_empty() {
+            return Err(ParseIntegerError::Empty);
+        }
+
+        if !digits.bytes().all(|digit| digit.is_ascii_digit()) {
+            return Err(ParseIntegerError::InvalidDigit);
+        }
+
+        let mut magnitude = Vec::new();
+        let first_chunk = match digits.len() % DECIMAL_CHUNK_DIGITS {
+            0 => DECIMAL_CHUNK_DIGITS,
+            length => length,
+        };
+
+        let mut start = 0;
+        let mut end = first_chunk;
+
+        while start < digits.len() {
+            let chunk: u32 = digits[start..end].parse().map_err(|_| ParseIntegerError::InvalidDigit)?;
+            let scale = 10_u32.pow((end - start) as u32);
+
+            let mut carry = u64::from(chunk);
+
+            for limb in magnitude.iter_mut() {
+                let product = u64::from(*limb) * u64::from(scale) + carry;
+                *limb = product as u32;
+                carry = product >> 32;
+            }
+
+            if carry > 0 {
+                magnitude.push(carry as u32);
+            }
+
+            start = end;
+            end += DECIMAL_CHUNK_DIGITS;
+        }
+
+        Ok(Integer::from_parts(negative, magnitude))
+    }
+}
+
+impl fmt::Display for Integer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return f.pad_integral(true, "", "0");
+        }
+
+        let mut chunks = Vec::new();
+        let mut magnitude = self.magnitude.clone();
+
+        while !magnitude.is_empty() {
+            let (quotient, remainder) = div_rem_small(&magnitude, DECIMAL_CHUNK);
+            chunks.push(remainder);
+            magnitude = quotient;
+
+            while magnitude.last() == Some(&0) {
+                magnitude.pop();
+            }
+        }
+
+        let mut digits = chunks.pop().map(|chunk| chunk.to_string()).unwrap_or_default();
+
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{:09}", chunk));
+        }
+
+        f.pad_integral(!self.negative, "", &digits)
+    }
+}
+
+impl fmt::Debug for Integer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Serialize for Integer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.to_i128().and_then(|value| i64::try_from(value).ok()) {
+            Some(value) => serializer.serialize_i64(value),
+            None => serializer.collect_str(self),
+        }
+    }
+}
+
+struct IntegerVisitor;
+
+impl<'de> Visitor<'de> for IntegerVisitor {
+    type Value = Integer;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an integer or a string of decimal digits")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Integer, E> {
+        Ok(Integer::from(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Integer, E> {
+        Ok(Integer::from(value))
+    }
+
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<Integer, E> {
+        Ok(Integer::from(value))
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<Integer, E> {
+        Ok(Integer::from(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Integer, E> {
+        value.parse().map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for Integer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(IntegerVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Integer;
+
+    fn integer(value: &str) -> Integer {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let value = "-340282366920938463463374607431768211456123456789";
+
+        let result = integer(value).to_string();
+
+        assert_eq!(result, value);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!("".parse::<Integer>().is_err());
+        assert!("-".parse::<Integer>().is_err());
+        assert!("12a".parse::<Integer>().is_err());
+    }
+
+    #[test]
+    fn add() {
+        let result = integer("18446744073709551615") + Integer::from(1);
+        let expected_result = integer("18446744073709551616");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn sub() {
+        let result = Integer::from(5) - integer("18446744073709551616");
+        let expected_result = integer("-18446744073709551611");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn mul() {
+        let result = integer("123456789012345678901234567890") * integer("-987654321098765432109876543210");
+        let expected_result = integer("-121932631137021795226185032733622923332237463801111263526900");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn div_rem() {
+        let lhs = integer("121932631137021795226185032733622923332237463801111263526907");
+        let rhs = integer("987654321098765432109876543210");
+
+        let (quotient, remainder) = lhs.div_rem(&rhs);
+
+        assert_eq!(quotient, integer("123456789012345678901234567890"));
+        assert_eq!(remainder, Integer::from(7));
+    }
+
+    #[test]
+    fn div_rem_sign() {
+        let (quotient, remainder) = Integer::from(-7).div_rem(&Integer::from(2));
+
+        assert_eq!(quotient, Integer::from(-3));
+        assert_eq!(remainder, Integer::from(-1));
+        assert_eq!(Integer::from(-7).div_floor(&Integer::from(2)), Integer::from(-4));
+    }
+
+    #[test]
+    fn div_rem_matches_primitive() {
+        let values = [
+            0_i128, 1, -1, 7, -13, 4_294_967_295, 4_294_967_296, -18_446_744_073_709_551_557,
+            170_141_183_460_469_231_731_687_303_715_884_105_727, 99_999_999_999_999_999_999,
+        ];
+
+        for lhs in values.iter() {
+            for rhs in values.iter().filter(|value| **value != 0) {
+                let (quotient, remainder) = Integer::from(*lhs).div_rem(&Integer::from(*rhs));
+
+                assert_eq!(quotient, Integer::from(lhs / rhs));
+                assert_eq!(remainder, Integer::from(lhs % rhs));
+            }
+        }
+    }
+
+    #[test]
+    fn pow() {
+        let result = Integer::from(3).pow(100);
+        let expected_result = integer("515377520732011331036461129765621272702107522001");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn gcd() {
+        let result = integer("-1234567890123456789012").gcd(&integer("9876543210987654321098"));
+        let expected_result = Integer::from(2);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn root() {
+        let value = Integer::from(12345).pow(7);
+
+        assert_eq!(value.root(7), Some(Integer::from(12345)));
+        assert_eq!((value + Integer::one()).root(7), None);
+        assert_eq!(Integer::from(-27).root(3), Some(Integer::from(-3)));
+        assert_eq!(Integer::from(-4).root(2), None);
+    }
+
+    #[test]
+    fn compare() {
+        assert!(Integer::from(-5) < Integer::from(3));
+        assert!(integer("-18446744073709551616") < Integer::from(-5));
+        assert!(integer("18446744073709551616") > Integer::from(u64::MAX));
+    }
+
+    #[test]
+    fn to_i128() {
+        assert_eq!(Integer::from(i128::MIN).to_i128(), Some(i128::MIN));
+        assert_eq!(Integer::from(i128::MAX).to_i128(), Some(i128::MAX));
+        assert_eq!((Integer::from(i128::MAX) + Integer::one()).to_i128(), None);
+    }
+
+    #[test]
+    fn to_f64() {
+        assert_eq!(Integer::from(-42).to_f64(), -42.0);
+        assert_eq!(Integer::from(2).pow(200).to_f64(), 2_f64.powi(200));
+        assert_eq!(integer("9007199254740993").to_f64(), 9007199254740992.0);
+        assert_eq!(integer("9007199254740995").to_f64(), 9007199254740996.0);
+    }
+}
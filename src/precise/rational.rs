@@ -0,0 +1,581 @@
+use std::fmt;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::ops::{ Add, Sub, Mul, Div, Rem, Neg };
+use serde_derive::{ Serialize, Deserialize };
+use crate::precise::integer::Integer;
+
+const MAX_POWER_BITS: u64 = 1 << 20;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "RawRational")]
+pub struct Rational {
+    numerator: Integer,
+    denominator: Integer,
+}
+
+#[derive(Deserialize)]
+struct RawRational {
+    numerator: Integer,
+    denominator: Integer,
+}
+
+impl TryFrom<RawRational> for Rational {
+    type Error = &'static str;
+
+    fn try_from(raw: RawRational) -> Result<Rational, Self::Error> {
+        Rational::checked_new(raw.numerator, raw.denominator).ok_or("rational with zero denominator")
+    }
+}
+
+impl Rational {
+    pub fn new<N: Into<Integer>, D: Into<Integer>>(numerator: N, denominator: D) -> Rational {
+        Rational::checked_new(numerator, denominator).expect("rational with zero denominator")
+    }
+
+    pub fn checked_new<N: Into<Integer>, D: Into<Integer>>(numerator: N, denominator: D) -> Option<Rational> {
+        let numerator = numerator.into();
+        let denominator = denominator.into();
+
+        if denominator.is_zero() {
+            return None;
+        }
+
+        let gcd = numerator.gcd(&denominator);
+
+        let mut numerator = &numerator / &gcd;
+        let mut denominator = &denominator / &gcd;
+
+        if denominator.is_negative() {
+            numerator = -numerator;
+            denominator = -denominator;
+        }
+
+        Some(Rational {
+            numerator,
+            denominator,
+        })
+    }
+
+    pub fn from_f64(value: f64) -> Option<Rational> {
+        if !value.is_finite() {
+            return None;
+        }
+
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased_exponent - 1075)
+        };
+
+        let magnitude = if exponent >= 0 {
+            Rational::from(Integer::from(mantissa) << exponent as u64)
+        } else {
+            Rational::new(mantissa, Integer::one() << (-exponent) as u64)
+        };
+
+        Some(if value.is_sign_negative() { -magnitude } else { magnitude })
+    }
+
+    pub fn approximate_f64(value: f64, max_denominator: &Integer) -> Option<Rational> {
+        Rational::from_f64(value).map(|value| value.limit_denominator(max_denominator))
+    }
+
+    pub fn limit_denominator(&self, max_denominator: &Integer) -> Rational {
+        if *max_denominator < Integer::one() {
+            return self.limit_denominator(&Integer::one());
+        }
+
+        if self.denominator <= *max_denominator {
+            return self.clone();
+        }
+
+        let (mut p0, mut q0, mut p1, mut q1) = (Integer::zero(), Integer::one(), Integer::one(), Integer::zero());
+        let (mut numerator, mut denominator) = (self.numerator.clone(), self.denominator.clone());
+
+        loop {
+            let quotient = numerator.div_floor(&denominator);
+            let q2 = &q0 + &(&quotient * &q1);
+
+            if q2 > *max_denominator {
+                break;
+            }
+
+            let p2 = &p0 + &(&quotient * &p1);
+
+            p0 = p1;
+            q0 = q1;
+            p1 = p2;
+            q1 = q2;
+
+            let remainder = &numerator - &(&quotient * &denominator);
+
+            numerator = denominator;
+            denominator = remainder;
+        }
+
+        let steps = (max_denominator - &q0).div_floor(&q1);
+
+        let lower = Rational::new(&p0 + &(&steps * &p1), &q0 + &(&steps * &q1));
+        let upper = Rational::new(p1, q1);
+
+        if (&upper - self).abs() <= (&lower - self).abs() {
+            upper
+        } else {
+            lower
+        }
+    }
+
+    pub fn zero() -> Rational {
+        Rational::from(0)
+    }
+
+    pub fn one() -> Rational {
+        Rational::from(1)
+    }
+
+    pub fn numerator(&self) -> &Integer {
+        &self.numerator
+    }
+
+    pub fn denominator(&self) -> &Integer {
+        &self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.numerator.is_one() && self.denominator.is_one()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.numerator.is_negative()
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.numerator.is_positive()
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator.is_one()
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        let shift = 64 + self.denominator.bits() as i64 - self.numerator.bits() as i64;
+
+        let (numerator, denominator) = if shift >= 0 {
+            (self.numerator.clone() << shift as u64, self.denominator.clone())
+        } else {
+            (self.numerator.clone(), self.denominator.clone() << (-shift) as u64)
+        };
+
+        let (quotient, remainder) = numerator.div_rem(&denominator);
+
+        let quotient = if remainder.is_zero() {
+            quotient
+        } else {
+            (quotient << 1) + Integer::from(self.numerator.signum())
+        };
+
+        let scale = if remainder.is_zero() { shift } else { shift + 1 };
+
+        scale_f64(quotient.to_f64(), -scale)
+    }
+
+    pub fn abs(&self) -> Rational {
+        Rational {
+            numerator: self.numerator.abs(),
+            denominator: self.denominator.clone(),
+        }
+    }
+
+    pub fn recip(&self) -> Rational {
+        self.checked_recip().expect("reciprocal of zero")
+    }
+
+    pub fn checked_recip(&self) -> Option<Rational> {
+        Rational::checked_new(self.denominator.clone(), self.numerator.clone())
+    }
+
+    pub fn checked_pow(&self, exponent: &Integer) -> Option<Rational> {
+        let base = if exponent.is_negative() {
+            self.checked_recip()?
+        } else {
+            self.clone()
+        };
+
+        if (base.numerator.abs().is_one() && base.denominator.is_one()) || base.is_zero() {
+            return Some(if exponent.is_zero() || (base.is_negative() && exponent.is_even()) {
+                Rational::one()
+            } else {
+                base
+            });
+        }
+
+        let exponent = exponent.abs().to_u32()?;
+
+        let bits = base.numerator.bits().max(base.denominator.bits());
+
+        if bits.saturating_mul(u64::from(exponent)) > MAX_POWER_BITS {
+            return None;
+        }
+
+        Some(Rational {
+            numerator: base.numerator.pow(exponent),
+            denominator: base.denominator.pow(exponent),
+        })
+    }
+
+    pub fn root(&self, degree: &Integer) -> Option<Rational> {
+        if !degree.is_positive() {
+            return None;
+        }
+
+        if degree.is_one() || self.is_zero() || (self.abs().is_one() && (!self.is_negative() || !degree.is_even())) {
+            return Some(self.clone());
+        }
+
+        let degree = degree.to_u32()?;
+
+        Some(Rational {
+            numerator: self.numerator.root(degree)?,
+            denominator: self.denominator.root(degree)?,
+        })
+    }
+
+    pub fn trunc(&self) -> Rational {
+        Rational::from(&self.numerator / &self.denominator)
+    }
+
+    pub fn floor(&self) -> Rational {
+        Rational::from(self.numerator.div_floor(&self.denominator))
+    }
+
+    pub fn ceil(&self) -> Rational {
+        -(-self).floor()
+    }
+}
+
+pub(crate) fn scale_f64(value: f64, exponent: i64) -> f64 {
+    let mut value = value;
+    let mut exponent = exponent;
+
+    while exponent > 0 {
+        let step = exponent.min(1000);
+        value *= 2_f64.powi(step as i32);
+        exponent -= step;
+    }
+
+    while exponent < 0 {
+        let step = exponent.max(-1000);
+        value *= 2_f64.powi(step as i32);
+        exponent -= step;
+    }
+
+    value
+}
+
+impl From<Integer> for Rational {
+    fn from(value: Integer) -> Self {
+        Rational {
+            numerator: value,
+            denominator: Integer::one(),
+        }
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Rational::from(Integer::from(value))
+    }
+}
+
+impl<'a> Add<&'a Rational> for &'a Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: &Rational) -> Self::Output {
+        if self.denominator == rhs.denominator {
+            return Rational::new(&self.numerator + &rhs.numerator, self.denominator.clone());
+        }
+
+        Rational::new(
+            &self.numerator * &rhs.denominator + &rhs.numerator * &self.denominator,
+            &self.denominator * &rhs.denominator
+        )
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl<'a> Sub<&'a Rational> for &'a Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: &Rational) -> Self::Output {
+        self + &(-rhs)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl<'a> Mul<&'a Rational> for &'a Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: &Rational) -> Self::Output {
+        Rational::new(&self.numerator * &rhs.numerator, &self.denominator * &rhs.denominator)
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<'a> Div<&'a Rational> for &'a Rational {
+    type Output = Rational;
+
+    fn div(self, rhs: &Rational) -> Self::Output {
+        if rhs.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+
+        Rational::new(&self.numerator * &rhs.denominator, &self.denominator * &rhs.numerator)
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+impl<'a> Rem<&'a Rational> for &'a Rational {
+    type Output = Rational;
+
+    fn rem(self, rhs: &Rational) -> Self::Output {
+        let quotient = (self / rhs).trunc();
+
+        self - &(rhs * &quotient)
+    }
+}
+
+impl Rem for Rational {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        &self % &rhs
+    }
+}
+
+impl Neg for &Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Self::Output {
+        Rational {
+            numerator: -&self.numerator,
+            denominator: self.denominator.clone(),
+        }
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Rational {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        (&self.numerator * &rhs.denominator).cmp(&(&rhs.numerator * &self.denominator))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rational;
+    use crate::precise::integer::Integer;
+
+    #[test]
+    fn new() {
+        let result = Rational::new(10, -4);
+
+        assert_eq!(*result.numerator(), Integer::from(-5));
+        assert_eq!(*result.denominator(), Integer::from(2));
+    }
+
+    #[test]
+    fn checked_new_zero_denominator() {
+        let result = Rational::checked_new(1, 0);
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn add() {
+        let result = Rational::new(1, 6) + Rational::new(1, 3);
+        let expected_result = Rational::new(1, 2);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn sub() {
+        let result = Rational::new(1, 6) - Rational::new(1, 3);
+        let expected_result = Rational::new(-1, 6);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn mul() {
+        let result = Rational::new(2, 3) * Rational::new(9, 4);
+        let expected_result = Rational::new(3, 2);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn div() {
+        let result = Rational::new(2, 3) / Rational::new(4, 9);
+        let expected_result = Rational::new(3, 2);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn rem() {
+        let result = Rational::new(-7, 2) % Rational::from(2);
+        let expected_result = Rational::new(-3, 2);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn pow() {
+        let result = Rational::new(2, 3).checked_pow(&Integer::from(-3));
+        let expected_result = Some(Rational::new(27, 8));
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn pow_large() {
+        let result = Rational::from(10).checked_pow(&Integer::from(100)).unwrap();
+        let expected_result = Rational::from(format!("1{}", "0".repeat(100)).parse::<Integer>().unwrap());
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn pow_too_large() {
+        let result = Rational::from(10).checked_pow(&Integer::from(1_000_000_000));
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn root() {
+        assert_eq!(Rational::new(4, 9).root(&Integer::from(2)), Some(Rational::new(2, 3)));
+        assert_eq!(Rational::from(-27).root(&Integer::from(3)), Some(Rational::from(-3)));
+        assert_eq!(Rational::from(2).root(&Integer::from(2)), None);
+        assert_eq!(Rational::from(-4).root(&Integer::from(2)), None);
+    }
+
+    #[test]
+    fn compare() {
+        assert!(Rational::new(1, 3) < Rational::new(1, 2));
+        assert!(Rational::new(-1, 2) < Rational::new(-1, 3));
+    }
+
+    #[test]
+    fn floor_and_ceil() {
+        assert_eq!(Rational::new(-7, 2).floor(), Rational::from(-4));
+        assert_eq!(Rational::new(-7, 2).ceil(), Rational::from(-3));
+        assert_eq!(Rational::new(-7, 2).trunc(), Rational::from(-3));
+    }
+
+    #[test]
+    fn to_f64() {
+        assert_eq!(Rational::new(1, 3).to_f64(), 1.0 / 3.0);
+        assert_eq!(Rational::new(-5, 16).to_f64(), -5.0 / 16.0);
+        assert_eq!(Rational::new(Integer::from(10).pow(400), Integer::from(10).pow(399)).to_f64(), 10.0);
+    }
+
+    #[test]
+    fn from_f64() {
+        assert_eq!(Rational::from_f64(0.75), Some(Rational::new(3, 4)));
+        assert_eq!(Rational::from_f64(-1024.0), Some(Rational::from(-1024)));
+        assert_eq!(Rational::from_f64(0.1), Some(Rational::new(3602879701896397_i64, 36028797018963968_i64)));
+        assert_eq!(Rational::from_f64(f64::INFINITY), None);
+    }
+
+    #[test]
+    fn limit_denominator() {
+        let pi = Rational::from_f64(std::f64::consts::PI).unwrap();
+
+        assert_eq!(pi.limit_denominator(&Integer::from(10)), Rational::new(22, 7));
+        assert_eq!(pi.limit_denominator(&Integer::from(100)), Rational::new(311, 99));
+        assert_eq!(Rational::new(3, 4).limit_denominator(&Integer::from(4)), Rational::new(3, 4));
+        assert_eq!(Rational::new(1, 3).limit_denominator(&Integer::zero()), Rational::zero());
+        assert_eq!(Rational::new(-5, 3).limit_denominator(&Integer::from(-2)), Rational::from(-2));
+        assert_eq!(Rational::approximate_f64(2.7, &Integer::zero()), Some(Rational::from(3)));
+    }
+
+    #[test]
+    fn deserialize() {
+        let result: Rational = serde_json::from_str(r#"{"numerator":6,"denominator":-4}"#).unwrap();
+
+        assert_eq!(result, Rational::new(-3, 2));
+        assert_eq!(serde_json::from_str::<Rational>(&serde_json::to_string(&result).unwrap()).unwrap(), result);
+        assert!(serde_json::from_str::<Rational>(r#"{"numerator":1,"denominator":0}"#).is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Rational::new(6, 4).to_string(), "3/2");
+        assert_eq!(Rational::from(-3).to_string(), "-3");
+    }
+}
@@ -0,0 +1,264 @@
+use std::fmt;
+use crate::precise::equation::Equation;
+use crate::precise::expression::Expr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rule {
+    EvaluateArithmetic,
+    CombineLikeTerms,
+    MultiplyFactors,
+    ExpandProduct,
+    ExpandPower,
+    CancelCommonFactors,
+    SimplifyPower,
+    EvaluateFunction,
+    Normalize,
+    MoveTermsToOneSide,
+    LinearFormula,
+    QuadraticFormula,
+    AddToBothSides,
+    SubtractFromBothSides,
+    MultiplyBothSides,
+    DivideBothSides,
+    TakeRoot,
+    TakeLogarithm,
+    Exponentiate,
+    RaiseToPower,
+    SplitAbsoluteValue,
+    RejectExtraneous,
+}
+
+impl Rule {
+    pub fn name(self) -> &'static str {
+        match self {
+            Rule::EvaluateArithmetic => "evaluate arithmetic",
+            Rule::CombineLikeTerms => "combine like terms",
+            Rule::MultiplyFactors => "multiply factors",
+            Rule::ExpandProduct => "expand product",
+            Rule::ExpandPower => "expand power",
+            Rule::CancelCommonFactors => "cancel common factors",
+            Rule::SimplifyPower => "simplify power",
+            Rule::EvaluateFunction => "evaluate function",
+            Rule::Normalize => "normalize",
+            Rule::MoveTermsToOneSide => "move all terms to one side",
+            Rule::LinearFormula => "solve linear equation",
+            Rule::QuadraticFormula => "apply quadratic formula",
+            Rule::AddToBothSides => "add to both sides",
+            Rule::SubtractFromBothSides => "subtract from both sides",
+            Rule::MultiplyBothSides => "multiply both sides",
+            Rule::DivideBothSides => "divide both sides",
+            Rule::TakeRoot => "take root of both sides",
+            Rule::TakeLogarithm => "take logarithm of both sides",
+            Rule::Exponentiate => "exponentiate both sides",
+            Rule::RaiseToPower => "raise both sides to a power",
+            Rule::SplitAbsoluteValue => "split absolute value",
+            Rule::RejectExtraneous => "reject extraneous solution",
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Statement {
+    Expression(Expr),
+    Equation(Equation),
+}
+
+impl Statement {
+    pub fn to_latex(&self) -> String {
+        match self {
+            Statement::Expression(expression) => expression.to_latex(),
+            Statement::Equation(equation) => equation.to_latex(),
+        }
+    }
+}
+
+impl PartialEq for Statement {
+    fn eq(&self, rhs: &Self) -> bool {
+        match (self, rhs) {
+            (Statement::Expression(lhs), Statement::Expression(rhs)) => lhs.structural_eq(rhs),
+            (Statement::Equation(lhs), Statement::Equation(rhs)) => lhs == rhs,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Statement {}
+
+impl From<Expr> for Statement {
+    fn from(value: Expr) -> Self {
+        Statement::Expression(value)
+    }
+}
+
+impl From<Equation> for Statement {
+    fn from(value: Equation) -> Self {
+        Statement::Equation(value)
+    }
+}
+
+impl fmt::Display for Statement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Statement::Expression(expression) => write!(f, "{}", expression),
+            Statement::Equation(equation) => write!(f, "{}", equation),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Step {
+    rule: Rule,
+    before: Statement,
+    after: Statement,
+}
+
+impl Step {
+    pub fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    pub fn before(&self) -> &Statement {
+        &self.before
+    }
+
+    pub fn after(&self) -> &Statement {
+        &self.after
+    }
+}
+
+impl PartialEq for Step {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.rule == rhs.rule && self.before == rhs.before && self.after == rhs.after
+    }
+}
+
+impl Eq for Step {}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} => {} ({})", self.before, self.after, self.rule)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Trace {
+    steps: Vec<Step>,
+}
+
+impl Trace {
+    pub fn new() -> Trace {
+        Trace {
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn record<B: Into<Statement>, A: Into<Statement>>(&mut self, rule: Rule, before: B, after: A) {
+        self.steps.push(Step {
+            rule,
+            before: before.into(),
+            after: after.into(),
+        });
+    }
+
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn to_latex(&self) -> String {
+        let lines: Vec<String> = self.steps.iter()
+            .map(|step| format!("{} &\\Rightarrow {} && \\text{{{}}}", step.before.to_latex(), step.after.to_latex(), step.rule))
+            .collect();
+
+        format!("\\begin{{align*}}\n{}\n\\end{{align*}}", lines.join(" \\\\\n"))
+    }
+}
+
+impl PartialEq for Trace {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.steps == rhs.steps
+    }
+}
+
+impl Eq for Trace {}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, step) in self.steps.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+
+            write!(f, "{}. {}", index + 1, step)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ Rule, Statement, Trace };
+    use crate::precise::equation::Equation;
+    use crate::precise::expression::Expr;
+
+    fn parse(source: &str) -> Expr {
+        source.parse().unwrap()
+    }
+
+    #[test]
+    fn record() {
+        let mut trace = Trace::new();
+        assert!(trace.is_empty());
+
+        trace.record(Rule::CombineLikeTerms, parse("x + x"), parse("2x"));
+
+        assert_eq!(trace.len(), 1);
+        assert_eq!(trace.steps()[0].rule(), Rule::CombineLikeTerms);
+        assert_eq!(trace.steps()[0].before().to_string(), "x + x");
+    }
+
+    #[test]
+    fn text() {
+        let mut trace = Trace::new();
+        trace.record(Rule::SubtractFromBothSides, Equation::new(parse("x + 1"), 3), Equation::new(Expr::var("x"), 2));
+        trace.record(Rule::EvaluateArithmetic, parse("1 + 1"), Expr::from(2));
+
+        assert_eq!(trace.to_string(), "1. x + 1 = 3 => x = 2 (subtract from both sides)\n2. 1 + 1 => 2 (evaluate arithmetic)");
+    }
+
+    #[test]
+    fn latex() {
+        let mut trace = Trace::new();
+        trace.record(Rule::DivideBothSides, Equation::new(parse("2x"), 1), Equation::new(Expr::var("x"), parse("1 / 2")));
+
+        assert_eq!(trace.to_latex(), "\\begin{align*}\n2x = 1 &\\Rightarrow x = \\frac{1}{2} && \\text{divide both sides}\n\\end{align*}");
+    }
+
+    #[test]
+    fn structural_equality() {
+        let mut lhs = Trace::new();
+        lhs.record(Rule::EvaluateArithmetic, parse("1 + 1"), Expr::from(2));
+
+        let mut rhs = Trace::new();
+        rhs.record(Rule::EvaluateArithmetic, parse("1 + 1"), parse("4 / 2"));
+
+        assert_ne!(lhs, rhs);
+        assert_eq!(lhs, lhs.clone());
+        assert_ne!(Statement::from(Expr::from(2)), Statement::from(parse("1 + 1")));
+        assert_ne!(Statement::from(Equation::new(parse("x"), 2)), Statement::from(Equation::new(parse("x"), parse("1 + 1"))));
+    }
+}